@@ -46,11 +46,18 @@ rustflags = ["-Zshare-generics=off"]
     let glm = Config::new("glm").cxxflag(cxxflags).build();
     println!("cargo:rustc-link-search=native={}", glm.display());
 
+    // Build Clipper2 from the submodule instead of downloading it or using a system copy
+    let clipper2 =
+        std::path::PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("clipper2");
+
     Config::new("manifold")
         .cxxflag(cxxflags) //  MSVC flag to enable exception handling
         .define("CMAKE_BUILD_TYPE", "Release")
         .define("CMAKE_INSTALL_LIBDIR", "lib")
-        .define("MANIFOLD_CROSS_SECTION", "OFF")
+        .define("MANIFOLD_CROSS_SECTION", "ON")
+        .define("MANIFOLD_USE_BUILTIN_CLIPPER2", "ON")
+        .define("FETCHCONTENT_SOURCE_DIR_CLIPPER2", clipper2)
+        .define("FETCHCONTENT_FULLY_DISCONNECTED", "ON")
         .define("MANIFOLD_TEST", "OFF")
         .define("BUILD_SHARED_LIBS", "OFF")
         .define("MANIFOLD_CBIND", "OFF")
@@ -69,6 +76,10 @@ rustflags = ["-Zshare-generics=off"]
     println!("cargo:rustc-link-search={out_dir}/lib");
     println!("cargo:rustc-link-lib=static=manifold");
 
+    // Clipper2 is built by manifold from the submodule for the cross section support
+    println!("cargo:rustc-link-search={out_dir}/build/_deps/clipper2-build");
+    println!("cargo:rustc-link-lib=static=Clipper2");
+
     println!("cargo:rerun-if-changed=src/lib.rs");
     println!("cargo:rerun-if-changed=src/manifold_rs.h");
     println!("cargo:rerun-if-changed=src/manifold_rs.cpp");
@@ -10,22 +10,19 @@ impl ManifoldPlugin {
         mut meshes: ResMut<Assets<Mesh>>,
         mut materials: ResMut<Assets<StandardMaterial>>,
     ) {
-        let tube = Self::cylinder_manifold(1.0, 3.0)
-            .boolean_op(
-                &Self::cylinder_manifold(0.5, 4.0),
-                manifold_rs::BooleanOp::Difference,
-            )
-            .translate(-1.5, -1.5, 0.0);
-
-        Self::add_manifold(&mut commands, &mut meshes, &mut materials, tube);
-
-        let tube_with_normals = Self::cylinder_manifold(1.0, 3.0)
-            .boolean_op(
-                &Self::cylinder_manifold(0.5, 4.0),
-                manifold_rs::BooleanOp::Difference,
-            )
-            .calculate_normals(0, 50.0)
-            .translate(1.5, 1.5, 0.0);
+        let tube = Self::cylinder_manifold(1.0, 3.0).boolean_op(
+            &Self::cylinder_manifold(0.5, 4.0),
+            manifold_rs::BooleanOp::Difference,
+        );
+
+        let tube_with_normals = tube.calculate_normals(0, 50.0).translate(1.5, 1.5, 0.0);
+
+        Self::add_manifold(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            tube.translate(-1.5, -1.5, 0.0),
+        );
 
         Self::add_manifold(
             &mut commands,
@@ -86,7 +83,11 @@ impl ManifoldPlugin {
 
     /// Convert Manifold to bevy mesh
     fn manifold_to_bevy_mesh(manifold: manifold_rs::Manifold) -> Mesh {
-        let mesh = manifold.to_mesh();
+        // Properties of this example are only ever normals from `calculate_normals(0, ..)`
+        let mesh = match manifold.num_prop() {
+            0 => manifold.to_mesh(),
+            _ => manifold.to_mesh_with_normals(0),
+        };
 
         let vertices = mesh.vertices();
         let indices = mesh.indices();
@@ -14,7 +14,7 @@ fn generate_circle(radius: f64, offset: (f64, f64), segments: usize) -> Vec<f64>
     circle
 }
 
-fn main() -> std::io::Result<()> {
+fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Write sphere to an STL file
     manifold_rs::Manifold::sphere(4.0, 128).write_stl_to_file("sphere.stl")?;
 
@@ -35,33 +35,19 @@ fn main() -> std::io::Result<()> {
         let circle = generate_circle(2.0, (4.0, 0.0), 32);
 
         // Revolve the circle 360° around the z-axis
-        let manifold = manifold_rs::Manifold::revolve(&[circle.as_slice()], 32, 360.0);
+        let manifold = manifold_rs::Manifold::revolve(&[circle.as_slice()], 32, 360.0)?;
 
         manifold.write_stl_to_file("torus.stl")?;
     }
 
-    // Generate a tube via `extrude` and write resulting mesh to an STL file
+    // Generate a tube via `extrude_shape` and write resulting mesh to an STL file
     {
-        // Generate circle with 32 vertices
-        let inner_circle = generate_circle(0.3, (0.0, 0.0), 32);
-        let outer_circle = generate_circle(1.0, (0.0, 0.0), 32);
-
-        // CCW winding order to create a hole in the tube
-        let inner_circle = inner_circle
-            .into_iter()
-            .enumerate()
-            .map(|(i, x)| if i % 2 == 0 { x } else { -x })
-            .collect::<Vec<_>>();
+        // Circle with a hole, the winding order is handled by the cross section
+        let ring = manifold_rs::CrossSection::circle(1.0, 32)
+            .difference(&manifold_rs::CrossSection::circle(0.3, 32));
 
-        // Extrude the circle along the z-axis
-        let manifold = manifold_rs::Manifold::extrude(
-            &[outer_circle.as_slice(), inner_circle.as_slice()],
-            4.0,
-            16,
-            0.0,
-            1.0,
-            1.0,
-        );
+        // Extrude the ring along the z-axis
+        let manifold = manifold_rs::Manifold::extrude_shape(&ring, 4.0, 16, 0.0, 1.0, 1.0)?;
 
         manifold.write_stl_to_file("tube.stl")?;
     }
@@ -79,7 +65,7 @@ fn main() -> std::io::Result<()> {
             0.0,
             1.0,
             1.0,
-        );
+        )?;
         let manifold = manifold.hull();
 
         manifold.write_stl_to_file("hull.stl")?;
@@ -0,0 +1,78 @@
+// Copyright © 2025 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Axis-aligned bounding boxes
+
+use crate::BoundingBox;
+
+/// Axis-aligned bounding rectangle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rect {
+    /// Minimum corner.
+    pub min: [f64; 2],
+    /// Maximum corner.
+    pub max: [f64; 2],
+}
+
+impl Rect {
+    /// Rectangle which contains no points.
+    pub const EMPTY: Self = Self {
+        min: [f64::INFINITY; 2],
+        max: [f64::NEG_INFINITY; 2],
+    };
+
+    /// Create a rectangle from its minimum and maximum corner.
+    pub fn new(min: [f64; 2], max: [f64; 2]) -> Self {
+        Self { min, max }
+    }
+
+    /// Get the center of the rectangle.
+    pub fn center(&self) -> [f64; 2] {
+        std::array::from_fn(|i| (self.min[i] + self.max[i]) / 2.0)
+    }
+
+    /// Get the size of the rectangle along each axis.
+    pub fn size(&self) -> [f64; 2] {
+        std::array::from_fn(|i| self.max[i] - self.min[i])
+    }
+
+    /// Does the rectangle contain no points?
+    pub fn is_empty(&self) -> bool {
+        (0..2).any(|i| self.min[i] > self.max[i])
+    }
+
+    /// Grow the rectangle to contain a point.
+    pub fn union_point(&self, point: [f64; 2]) -> Self {
+        Self {
+            min: std::array::from_fn(|i| self.min[i].min(point[i])),
+            max: std::array::from_fn(|i| self.max[i].max(point[i])),
+        }
+    }
+}
+
+impl BoundingBox {
+    /// Create a bounding box from its minimum and maximum corner.
+    pub fn new(min: [f64; 3], max: [f64; 3]) -> Self {
+        Self { min, max }
+    }
+
+    /// Get the center of the bounding box.
+    pub fn center(&self) -> [f64; 3] {
+        std::array::from_fn(|i| (self.min[i] + self.max[i]) / 2.0)
+    }
+
+    /// Get the size of the bounding box along each axis.
+    pub fn size(&self) -> [f64; 3] {
+        std::array::from_fn(|i| self.max[i] - self.min[i])
+    }
+
+    /// Do the two bounding boxes overlap?
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..3).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    /// Does the bounding box contain no points?
+    pub fn is_empty(&self) -> bool {
+        (0..3).any(|i| self.min[i] > self.max[i])
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright © 2025 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Rust closures called back from C++
+//!
+//! Each context wraps a borrowed closure and is passed through C++ as opaque type.
+//! A panic inside a closure aborts the process because it cannot unwind through C++.
+
+/// Closure called for each vertex by [`crate::Manifold::warp`].
+pub struct WarpContext<'a>(pub &'a mut dyn FnMut(&mut [f64; 3]));
+
+/// Closure called for all vertices by [`crate::Manifold::warp_batch`].
+pub struct WarpBatchContext<'a>(pub &'a mut dyn FnMut(&mut [[f64; 3]]));
+
+/// Closure receiving new properties, position and old properties of a vertex.
+type PropertiesFn<'a> = dyn FnMut(&mut [f64], [f64; 3], &[f64]) + 'a;
+
+/// Closure called for each vertex by [`crate::Manifold::set_properties`].
+pub struct PropertiesContext<'a>(pub &'a mut PropertiesFn<'a>);
+
+/// Signed distance function sampled by [`crate::Manifold::from_sdf`].
+pub struct SdfContext<'a>(pub &'a (dyn Fn([f64; 3]) -> f64 + Sync));
+
+/// Called from C++ with the x, y, z coordinates of one vertex.
+pub fn warp_vertex(ctx: &mut WarpContext, vertex: &mut [f64]) {
+    (ctx.0)(vertex.try_into().expect("vertex must have 3 coordinates"))
+}
+
+/// Called from C++ with the flat x, y, z coordinates of all vertices.
+pub fn warp_vertices(ctx: &mut WarpBatchContext, vertices: &mut [f64]) {
+    let (vertices, rest) = vertices.as_chunks_mut::<3>();
+    debug_assert!(rest.is_empty());
+    (ctx.0)(vertices)
+}
+
+/// Called from C++ with the new and old properties and the position of one vertex.
+pub fn vertex_properties(
+    ctx: &mut PropertiesContext,
+    new_prop: &mut [f64],
+    x: f64,
+    y: f64,
+    z: f64,
+    old_prop: &[f64],
+) {
+    (ctx.0)(new_prop, [x, y, z], old_prop)
+}
+
+/// Called from C++, possibly from several threads, to sample the distance function.
+pub fn sdf_value(ctx: &SdfContext, x: f64, y: f64, z: f64) -> f64 {
+    (ctx.0)([x, y, z])
+}
@@ -0,0 +1,237 @@
+// Copyright © 2025 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! 2D cross sections
+
+use crate::{
+    error::{validate_polygons, validate_simple_polygons},
+    ffi, BooleanOp, InputError, Polygons,
+};
+
+/// 2D shape which can be extruded or revolved into a [`crate::Manifold`].
+pub trait Shape2D {
+    /// Get the contours with counter-clockwise outlines and clockwise holes.
+    ///
+    /// Fails if the contours intersect each other.
+    fn oriented_polygons(&self) -> Result<Polygons, InputError>;
+}
+
+/// A cross section is always properly oriented.
+impl Shape2D for CrossSection {
+    fn oriented_polygons(&self) -> Result<Polygons, InputError> {
+        Ok(self.to_polygons())
+    }
+}
+
+/// Holes are determined by nesting, the winding order of the contours is ignored.
+impl Shape2D for Polygons {
+    fn oriented_polygons(&self) -> Result<Polygons, InputError> {
+        let data = (0..self.size())
+            .map(|i| self.get_as_slice(i))
+            .collect::<Vec<_>>();
+        validate_polygons(&data)?;
+        validate_simple_polygons(&data)?;
+        Ok(CrossSection::from_polygons(self, FillRule::EvenOdd).to_polygons())
+    }
+}
+
+/// Rule to decide which regions of overlapping contours are filled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FillRule {
+    /// Regions with an odd winding number are filled.
+    EvenOdd,
+    /// Regions with a non-zero winding number are filled.
+    NonZero,
+    /// Regions with a positive winding number are filled, i.e. counter-clockwise contours.
+    #[default]
+    Positive,
+    /// Regions with a negative winding number are filled, i.e. clockwise contours.
+    Negative,
+}
+
+/// How corners are joined when offsetting a cross section.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JoinType {
+    /// Corners are squared off at the offset distance.
+    #[default]
+    Square,
+    /// Corners are rounded with an arc.
+    Round,
+    /// Edges are extended until they meet, up to the miter limit.
+    Miter,
+}
+
+/// Manifold rust wrapper for C++ cross section object.
+///
+/// A cross section is a set of non-overlapping 2D contours with
+/// counter-clockwise outlines and clockwise holes.
+pub struct CrossSection(pub(crate) cxx::UniquePtr<ffi::CrossSection>);
+
+impl CrossSection {
+    /// Create an empty cross section.
+    pub fn empty() -> Self {
+        Self(ffi::cross_section_empty())
+    }
+
+    /// Create a rectangle, with its corner or its center at the origin.
+    pub fn square(x_size: f64, y_size: f64, center: bool) -> Self {
+        Self(ffi::cross_section_square(x_size, y_size, center))
+    }
+
+    /// Create a circle at the origin.
+    pub fn circle(radius: f64, segments: u32) -> Self {
+        Self(ffi::cross_section_circle(radius, segments as i32))
+    }
+
+    /// Create a cross section from polygons, overlaps are resolved by the fill rule.
+    pub fn from_polygons(polygons: &Polygons, fill_rule: FillRule) -> Self {
+        let fill_rule = match fill_rule {
+            FillRule::EvenOdd => 0,
+            FillRule::NonZero => 1,
+            FillRule::Positive => 2,
+            FillRule::Negative => 3,
+        };
+        Self(ffi::cross_section_from_polygons(&polygons.0, fill_rule))
+    }
+
+    /// Create a cross section from polygons given as flat lists of x, y coordinates.
+    pub fn from_polygon_data(
+        multi_polygon_data: &[&[f64]],
+        fill_rule: FillRule,
+    ) -> Result<Self, InputError> {
+        validate_polygons(multi_polygon_data)?;
+        let polygons = Polygons(ffi::polygons_from_data(multi_polygon_data));
+        Ok(Self::from_polygons(&polygons, fill_rule))
+    }
+
+    /// Does the cross section contain no contours?
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Get the area of the cross section.
+    pub fn area(&self) -> f64 {
+        self.0.area()
+    }
+
+    /// Get the number of vertices.
+    pub fn num_vert(&self) -> usize {
+        self.0.num_vert()
+    }
+
+    /// Get the number of contours.
+    pub fn num_contour(&self) -> usize {
+        self.0.num_contour()
+    }
+
+    /// Get the union of two cross sections.
+    pub fn union(&self, b: &Self) -> Self {
+        Self(ffi::cross_section_union(&self.0, &b.0))
+    }
+
+    /// Get the intersection of two cross sections.
+    pub fn intersection(&self, b: &Self) -> Self {
+        Self(ffi::cross_section_intersection(&self.0, &b.0))
+    }
+
+    /// Get the difference of two cross sections.
+    pub fn difference(&self, b: &Self) -> Self {
+        Self(ffi::cross_section_difference(&self.0, &b.0))
+    }
+
+    /// Boolean operation on cross sections.
+    pub fn boolean_op(&self, b: &Self, op: BooleanOp) -> Self {
+        match op {
+            BooleanOp::Union => self.union(b),
+            BooleanOp::Intersection => self.intersection(b),
+            BooleanOp::Difference => self.difference(b),
+        }
+    }
+
+    /// Offset the contours by `delta`, negative values shrink the cross section.
+    ///
+    /// `miter_limit` limits the length of [`JoinType::Miter`] corners as multiple of `delta`.
+    /// `segments` is the number of segments of a full circle for [`JoinType::Round`] corners,
+    /// zero selects a default.
+    pub fn offset(&self, delta: f64, join_type: JoinType, miter_limit: f64, segments: u32) -> Self {
+        let join_type = match join_type {
+            JoinType::Square => 0,
+            JoinType::Round => 1,
+            JoinType::Miter => 2,
+        };
+        Self(
+            self.0
+                .offset(delta, join_type, miter_limit, segments as i32),
+        )
+    }
+
+    /// Convex hull.
+    pub fn hull(&self) -> Self {
+        Self(self.0.hull())
+    }
+
+    /// Translate the cross section.
+    pub fn translate(&self, x: f64, y: f64) -> Self {
+        Self(self.0.translate(x, y))
+    }
+
+    /// Rotate the cross section counter-clockwise around the origin.
+    pub fn rotate(&self, degrees: f64) -> Self {
+        Self(self.0.rotate(degrees))
+    }
+
+    /// Scale the cross section.
+    pub fn scale(&self, x: f64, y: f64) -> Self {
+        Self(self.0.scale(x, y))
+    }
+
+    /// Mirror the cross section over the line through the origin with the normal `(x, y)`.
+    pub fn mirror(&self, x: f64, y: f64) -> Self {
+        Self(self.0.mirror(x, y))
+    }
+
+    /// Get the contours as polygons.
+    pub fn to_polygons(&self) -> Polygons {
+        Polygons(self.0.to_polygons())
+    }
+}
+
+/// Convert Polygons to CrossSection with the [`FillRule::Positive`] fill rule
+impl From<&Polygons> for CrossSection {
+    fn from(polygons: &Polygons) -> Self {
+        Self::from_polygons(polygons, FillRule::Positive)
+    }
+}
+
+/// Convert CrossSection to Polygons
+impl From<&CrossSection> for Polygons {
+    fn from(cross_section: &CrossSection) -> Self {
+        cross_section.to_polygons()
+    }
+}
+
+#[test]
+fn test_cross_section() {
+    let square = CrossSection::square(2.0, 2.0, true);
+    assert!((square.area() - 4.0).abs() < 1e-9);
+    assert_eq!(square.num_vert(), 4);
+
+    let frame = square.difference(&CrossSection::square(1.0, 1.0, true));
+    assert!((frame.area() - 3.0).abs() < 1e-9);
+    assert_eq!(frame.num_contour(), 2);
+
+    let grown = square.offset(1.0, JoinType::Miter, 2.0, 0);
+    assert!((grown.area() - 16.0).abs() < 1e-6);
+    let grown = square.offset(1.0, JoinType::Round, 2.0, 64);
+    assert!((grown.area() - (4.0 + 8.0 + std::f64::consts::PI)).abs() < 0.01);
+
+    let pair = square.union(&square.translate(4.0, 0.0));
+    assert!((pair.hull().area() - 12.0).abs() < 1e-9);
+
+    let round_trip = CrossSection::from(&frame.to_polygons());
+    assert!((round_trip.area() - 3.0).abs() < 1e-9);
+
+    let triangle = [0.0, 0.0, 1.0, 0.0, 0.0, 1.0];
+    let triangle = CrossSection::from_polygon_data(&[&triangle], FillRule::Positive).unwrap();
+    assert!((triangle.area() - 0.5).abs() < 1e-9);
+}
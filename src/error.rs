@@ -0,0 +1,430 @@
+// Copyright © 2025 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Error types
+
+/// Error reported by a manifold operation.
+///
+/// The variants mirror the C++ `manifold::Manifold::Error` codes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ManifoldError {
+    /// A vertex position is NaN or infinite.
+    NonFiniteVertex,
+    /// The mesh is not a closed 2-manifold.
+    NotManifold,
+    /// A triangle references a vertex that does not exist.
+    VertexOutOfBounds,
+    /// The length of the vertex properties is not a multiple of the number of properties.
+    PropertiesWrongLength,
+    /// The number of properties is smaller than 3.
+    MissingPositionProperties,
+    /// The merge vectors have different lengths.
+    MergeVectorsDifferentLengths,
+    /// A merge vector references a vertex that does not exist.
+    MergeIndexOutOfBounds,
+    /// The run transforms have the wrong length.
+    TransformWrongLength,
+    /// The run indices have the wrong length.
+    RunIndexWrongLength,
+    /// The face IDs have the wrong length.
+    FaceIdWrongLength,
+    /// The construction parameters are invalid.
+    InvalidConstruction,
+    /// The result is too large to be represented.
+    ResultTooLarge,
+    /// Error code unknown to this version of the wrapper.
+    Unknown(i32),
+    /// The input was rejected before it was passed to C++.
+    InvalidInput(InputError),
+}
+
+impl ManifoldError {
+    /// Convert a C++ status code into a result.
+    pub(crate) fn from_status(code: i32) -> Result<(), Self> {
+        Err(match code {
+            0 => return Ok(()),
+            1 => Self::NonFiniteVertex,
+            2 => Self::NotManifold,
+            3 => Self::VertexOutOfBounds,
+            4 => Self::PropertiesWrongLength,
+            5 => Self::MissingPositionProperties,
+            6 => Self::MergeVectorsDifferentLengths,
+            7 => Self::MergeIndexOutOfBounds,
+            8 => Self::TransformWrongLength,
+            9 => Self::RunIndexWrongLength,
+            10 => Self::FaceIdWrongLength,
+            11 => Self::InvalidConstruction,
+            12 => Self::ResultTooLarge,
+            code => Self::Unknown(code),
+        })
+    }
+}
+
+impl std::fmt::Display for ManifoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NonFiniteVertex => write!(f, "non-finite vertex"),
+            Self::NotManifold => write!(f, "mesh is not manifold"),
+            Self::VertexOutOfBounds => write!(f, "vertex index out of bounds"),
+            Self::PropertiesWrongLength => write!(f, "vertex properties have wrong length"),
+            Self::MissingPositionProperties => write!(f, "missing position properties"),
+            Self::MergeVectorsDifferentLengths => write!(f, "merge vectors have different lengths"),
+            Self::MergeIndexOutOfBounds => write!(f, "merge index out of bounds"),
+            Self::TransformWrongLength => write!(f, "run transforms have wrong length"),
+            Self::RunIndexWrongLength => write!(f, "run indices have wrong length"),
+            Self::FaceIdWrongLength => write!(f, "face IDs have wrong length"),
+            Self::InvalidConstruction => write!(f, "invalid construction"),
+            Self::ResultTooLarge => write!(f, "result too large"),
+            Self::Unknown(code) => write!(f, "unknown manifold error (code {code})"),
+            Self::InvalidInput(err) => write!(f, "invalid input: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ManifoldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidInput(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<InputError> for ManifoldError {
+    fn from(err: InputError) -> Self {
+        Self::InvalidInput(err)
+    }
+}
+
+/// Error in mesh or polygon data passed to the wrapper.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InputError {
+    /// The number of vertex properties is not a multiple of the properties per vertex.
+    VerticesWrongLength {
+        /// Number of vertex properties.
+        len: usize,
+        /// Number of properties per vertex.
+        num_prop: usize,
+    },
+    /// Fewer than the 3 position properties per vertex.
+    MissingPositionProperties {
+        /// Number of properties per vertex.
+        num_prop: usize,
+    },
+    /// The number of indices is not a multiple of 3.
+    IndicesWrongLength {
+        /// Number of indices.
+        len: usize,
+    },
+    /// A triangle references a vertex that does not exist.
+    IndexOutOfBounds {
+        /// Index of the triangle.
+        triangle: usize,
+        /// The offending vertex index.
+        index: u64,
+        /// Number of vertices in the mesh.
+        num_vert: usize,
+    },
+    /// A vertex coordinate is NaN or infinite.
+    NonFiniteVertex {
+        /// Index of the vertex.
+        vertex: usize,
+    },
+    /// The number of coordinates of a polygon is odd.
+    PolygonWrongLength {
+        /// Index of the polygon.
+        polygon: usize,
+        /// Number of coordinates.
+        len: usize,
+    },
+    /// A polygon vertex coordinate is NaN or infinite.
+    NonFinitePolygonVertex {
+        /// Index of the polygon.
+        polygon: usize,
+        /// Index of the vertex within the polygon.
+        vertex: usize,
+    },
+    /// Two polygon edges intersect each other.
+    SelfIntersection {
+        /// Index of the polygon of the first edge.
+        polygon: usize,
+        /// Index of the start vertex of the first edge.
+        edge: usize,
+        /// Index of the polygon of the second edge.
+        other_polygon: usize,
+        /// Index of the start vertex of the second edge.
+        other_edge: usize,
+    },
+    /// The merge vectors have different lengths.
+    MergeVectorsDifferentLengths {
+        /// Length of the vector of source vertices.
+        from_len: usize,
+        /// Length of the vector of target vertices.
+        to_len: usize,
+    },
+    /// A merge vector references a vertex that does not exist.
+    MergeIndexOutOfBounds {
+        /// The offending vertex index.
+        index: u32,
+        /// Number of vertices in the mesh.
+        num_vert: usize,
+    },
+    /// A direction or normal vector is zero or not finite.
+    InvalidDirection,
+    /// An edge length is zero, negative or not finite.
+    InvalidEdgeLength,
+    /// A bounding box is not finite or its minimum exceeds its maximum.
+    InvalidBounds,
+    /// A layer height is zero, negative or not finite.
+    InvalidLayerHeight,
+    /// A range of layers is not finite or has too many layers.
+    InvalidLayerRange,
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::VerticesWrongLength { len, num_prop } => write!(
+                f,
+                "number of vertex properties ({len}) is not a multiple of {num_prop}"
+            ),
+            Self::MissingPositionProperties { num_prop } => write!(
+                f,
+                "vertices have {num_prop} properties, but at least 3 are required"
+            ),
+            Self::IndicesWrongLength { len } => {
+                write!(f, "number of indices ({len}) is not a multiple of 3")
+            }
+            Self::IndexOutOfBounds {
+                triangle,
+                index,
+                num_vert,
+            } => write!(
+                f,
+                "triangle {triangle} references vertex {index}, but the mesh has only {num_vert} vertices"
+            ),
+            Self::NonFiniteVertex { vertex } => write!(f, "vertex {vertex} is not finite"),
+            Self::PolygonWrongLength { polygon, len } => write!(
+                f,
+                "polygon {polygon} has an odd number of coordinates ({len})"
+            ),
+            Self::NonFinitePolygonVertex { polygon, vertex } => {
+                write!(f, "vertex {vertex} of polygon {polygon} is not finite")
+            }
+            Self::SelfIntersection {
+                polygon,
+                edge,
+                other_polygon,
+                other_edge,
+            } => write!(
+                f,
+                "edge {edge} of polygon {polygon} intersects edge {other_edge} of polygon {other_polygon}"
+            ),
+            Self::MergeVectorsDifferentLengths { from_len, to_len } => write!(
+                f,
+                "merge vectors have different lengths ({from_len} and {to_len})"
+            ),
+            Self::MergeIndexOutOfBounds { index, num_vert } => write!(
+                f,
+                "merge references vertex {index}, but the mesh has only {num_vert} vertices"
+            ),
+            Self::InvalidDirection => write!(f, "direction is zero or not finite"),
+            Self::InvalidEdgeLength => write!(f, "edge length is not finite and positive"),
+            Self::InvalidBounds => write!(f, "bounds are not finite or inverted"),
+            Self::InvalidLayerHeight => write!(f, "layer height is not finite and positive"),
+            Self::InvalidLayerRange => write!(f, "layer range is not finite or too large"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Check that vertex properties and indices describe a triangle mesh.
+pub(crate) fn validate_mesh<V, I>(
+    num_prop: usize,
+    vertices: &[V],
+    indices: &[I],
+) -> Result<(), InputError>
+where
+    V: Copy + Into<f64>,
+    I: Copy + Into<u64>,
+{
+    if num_prop < 3 {
+        return Err(InputError::MissingPositionProperties { num_prop });
+    }
+    if !vertices.len().is_multiple_of(num_prop) {
+        return Err(InputError::VerticesWrongLength {
+            len: vertices.len(),
+            num_prop,
+        });
+    }
+    if !indices.len().is_multiple_of(3) {
+        return Err(InputError::IndicesWrongLength { len: indices.len() });
+    }
+
+    if let Some(vertex) = vertices
+        .chunks_exact(num_prop)
+        .position(|v| v[..3].iter().any(|&x| !x.into().is_finite()))
+    {
+        return Err(InputError::NonFiniteVertex { vertex });
+    }
+
+    let num_vert = vertices.len() / num_prop;
+    if let Some(i) = indices.iter().position(|&i| i.into() >= num_vert as u64) {
+        return Err(InputError::IndexOutOfBounds {
+            triangle: i / 3,
+            index: indices[i].into(),
+            num_vert,
+        });
+    }
+
+    Ok(())
+}
+
+/// Check that merge vectors pair up existing vertices.
+pub(crate) fn validate_merge(
+    num_vert: usize,
+    merge_from_vert: &[u32],
+    merge_to_vert: &[u32],
+) -> Result<(), InputError> {
+    if merge_from_vert.len() != merge_to_vert.len() {
+        return Err(InputError::MergeVectorsDifferentLengths {
+            from_len: merge_from_vert.len(),
+            to_len: merge_to_vert.len(),
+        });
+    }
+    match merge_from_vert
+        .iter()
+        .chain(merge_to_vert)
+        .find(|&&i| i as usize >= num_vert)
+    {
+        Some(&index) => Err(InputError::MergeIndexOutOfBounds { index, num_vert }),
+        None => Ok(()),
+    }
+}
+
+/// Check that each polygon is a list of finite x, y pairs.
+pub(crate) fn validate_polygons(multi_polygon_data: &[&[f64]]) -> Result<(), InputError> {
+    multi_polygon_data
+        .iter()
+        .enumerate()
+        .try_for_each(|(polygon, data)| {
+            if !data.len().is_multiple_of(2) {
+                return Err(InputError::PolygonWrongLength {
+                    polygon,
+                    len: data.len(),
+                });
+            }
+            match data
+                .chunks_exact(2)
+                .position(|v| v.iter().any(|x| !x.is_finite()))
+            {
+                Some(vertex) => Err(InputError::NonFinitePolygonVertex { polygon, vertex }),
+                None => Ok(()),
+            }
+        })
+}
+
+/// Check that no polygon edges intersect, except adjacent edges at their common vertex.
+pub(crate) fn validate_simple_polygons(multi_polygon_data: &[&[f64]]) -> Result<(), InputError> {
+    /// Polygon edge with index of the polygon and of its start vertex
+    struct Edge {
+        polygon: usize,
+        index: usize,
+        a: [f64; 2],
+        b: [f64; 2],
+    }
+
+    impl Edge {
+        fn min_x(&self) -> f64 {
+            self.a[0].min(self.b[0])
+        }
+
+        fn max_x(&self) -> f64 {
+            self.a[0].max(self.b[0])
+        }
+    }
+
+    fn orientation(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+        (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+    }
+
+    // Is `c` within the bounding box of `a` and `b`?
+    fn within(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> bool {
+        (0..2).all(|i| a[i].min(b[i]) <= c[i] && c[i] <= a[i].max(b[i]))
+    }
+
+    fn intersect(e: &Edge, f: &Edge) -> bool {
+        let d1 = orientation(f.a, f.b, e.a);
+        let d2 = orientation(f.a, f.b, e.b);
+        let d3 = orientation(e.a, e.b, f.a);
+        let d4 = orientation(e.a, e.b, f.b);
+        (d1 * d2 < 0.0 && d3 * d4 < 0.0)
+            || (d1 == 0.0 && within(f.a, f.b, e.a))
+            || (d2 == 0.0 && within(f.a, f.b, e.b))
+            || (d3 == 0.0 && within(e.a, e.b, f.a))
+            || (d4 == 0.0 && within(e.a, e.b, f.b))
+    }
+
+    let mut edges = Vec::new();
+    let mut num_edges = Vec::new();
+    for (polygon, data) in multi_polygon_data.iter().enumerate() {
+        let (points, _) = data.as_chunks::<2>();
+        // Skip repeated points, they would create empty edges
+        let mut vertices = points
+            .iter()
+            .enumerate()
+            .filter(|(i, p)| *p != &points[(i + 1) % points.len()])
+            .map(|(i, p)| (i, *p))
+            .collect::<Vec<_>>();
+        if vertices.len() < 3 {
+            vertices.clear();
+        }
+        num_edges.push(vertices.len());
+        edges.extend((0..vertices.len()).map(|i| Edge {
+            polygon,
+            index: vertices[i].0,
+            a: vertices[i].1,
+            b: vertices[(i + 1) % vertices.len()].1,
+        }));
+    }
+
+    // Sweep along x to compare only edges with overlapping x range
+    let mut order = (0..edges.len()).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| edges[i].min_x().total_cmp(&edges[j].min_x()));
+
+    for (k, &i) in order.iter().enumerate() {
+        let e = &edges[i];
+        for &j in &order[k + 1..] {
+            let f = &edges[j];
+            if f.min_x() > e.max_x() {
+                break;
+            }
+            if e.polygon == f.polygon {
+                // Adjacent edges share a vertex
+                let n = num_edges[e.polygon];
+                let (lo, hi) = (i.min(j), i.max(j));
+                if hi - lo == 1 || hi - lo == n - 1 {
+                    continue;
+                }
+            }
+            if intersect(e, f) {
+                let (e, f) = if (e.polygon, e.index) < (f.polygon, f.index) {
+                    (e, f)
+                } else {
+                    (f, e)
+                };
+                return Err(InputError::SelfIntersection {
+                    polygon: e.polygon,
+                    edge: e.index,
+                    other_polygon: f.polygon,
+                    other_edge: f.index,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -3,11 +3,67 @@
 
 //! Rust integration of C++ library *Manifold* for geometric operations
 
+mod bounding_box;
+mod callback;
+mod cross_section;
+mod error;
+mod mesh_run;
+mod ops;
 #[cfg(feature = "output")]
 pub mod output;
+mod plane;
+mod polygons;
+mod transform;
+
+pub use bounding_box::Rect;
+pub use cross_section::*;
+pub use error::*;
+pub use ffi::BoundingBox;
+pub use mesh_run::{MeshRun, MeshRuns};
+pub use plane::PlaneFrame;
+pub use polygons::{Polygons, PolygonsIter};
+pub use transform::Transform;
+
+use callback::*;
+use error::{validate_merge, validate_mesh, validate_polygons};
 
 #[cxx::bridge(namespace = "manifold_rs")]
 mod ffi {
+    /// Axis-aligned bounding box.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct BoundingBox {
+        /// Minimum corner.
+        min: [f64; 3],
+        /// Maximum corner.
+        max: [f64; 3],
+    }
+
+    // Rust types and signatures exposed to C++.
+    extern "Rust" {
+        type WarpContext<'a>;
+
+        type WarpBatchContext<'a>;
+
+        type PropertiesContext<'a>;
+
+        type SdfContext<'a>;
+
+        fn warp_vertex(ctx: &mut WarpContext, vertex: &mut [f64]);
+
+        fn warp_vertices(ctx: &mut WarpBatchContext, vertices: &mut [f64]);
+
+        fn vertex_properties(
+            ctx: &mut PropertiesContext,
+            new_prop: &mut [f64],
+            x: f64,
+            y: f64,
+            z: f64,
+            old_prop: &[f64],
+        );
+
+        fn sdf_value(ctx: &SdfContext, x: f64, y: f64, z: f64) -> f64;
+    }
+
     // C++ types and signatures exposed to Rust.
     unsafe extern "C++" {
         include!("manifold_rs.h");
@@ -20,14 +76,75 @@ mod ffi {
         /// Get the number of vertices in a polygon.
         fn get_as_slice(self: &Polygons, i: usize) -> &[f64];
 
+        /// Append a polygon given as flat x, y coordinates.
+        fn push(self: Pin<&mut Polygons>, polygon: &[f64]);
+
+        /// Copy the polygons.
+        fn copy(self: &Polygons) -> UniquePtr<Polygons>;
+
+        /// Triangulate the polygons, three indices per triangle.
+        fn triangulate(self: &Polygons, epsilon: f64) -> UniquePtr<CxxVector<u32>>;
+
+        /// Create empty polygons.
+        fn polygons_empty() -> UniquePtr<Polygons>;
+
+        /// Vector of polygons, one entry per slice.
+        type PolygonsVec;
+
+        /// Get the number of entries.
+        fn size(self: &PolygonsVec) -> usize;
+
+        /// Move the polygons out of an entry.
+        fn take(self: Pin<&mut PolygonsVec>, i: usize) -> UniquePtr<Polygons>;
+
         /// Manifold object, wrapper for C++ manifold object.
         type Manifold;
 
         fn is_empty(self: &Manifold) -> bool;
 
+        /// Copy the manifold, sharing the CSG tree.
+        fn copy(self: &Manifold) -> UniquePtr<Manifold>;
+
+        /// Get the error status of the manifold.
+        fn status(self: &Manifold) -> i32;
+
+        /// Get the volume of the manifold.
+        fn volume(self: &Manifold) -> f64;
+
+        /// Get the surface area of the manifold.
+        fn surface_area(self: &Manifold) -> f64;
+
+        /// Get the genus of the manifold.
+        fn genus(self: &Manifold) -> i32;
+
+        /// Get the number of vertices.
+        fn num_vert(self: &Manifold) -> usize;
+
+        /// Get the number of edges.
+        fn num_edge(self: &Manifold) -> usize;
+
+        /// Get the number of triangles.
+        fn num_tri(self: &Manifold) -> usize;
+
+        /// Get the number of vertex properties.
+        fn num_prop(self: &Manifold) -> usize;
+
+        /// Get the bounding box of the manifold.
+        fn bounding_box(self: &Manifold) -> BoundingBox;
+
+        /// Get the tolerance of the manifold.
+        fn tolerance(self: &Manifold) -> f64;
+
+        /// Get the minimum gap to another manifold up to a search length.
+        fn min_gap(self: &Manifold, other: &Manifold, search_length: f64) -> f64;
+
         /// Slice the manifold into a set of polygons.
         fn slice(self: &Manifold, height: f64) -> UniquePtr<Polygons>;
 
+        /// Slice the manifold at several heights.
+        fn slice_layers(self: &Manifold, heights: &[f64], parallel: bool)
+            -> UniquePtr<PolygonsVec>;
+
         /// Project the manifold onto a plane and return the resulting polygons.
         fn project(self: &Manifold) -> UniquePtr<Polygons>;
 
@@ -51,6 +168,48 @@ mod ffi {
             segments: u32,
         ) -> UniquePtr<Manifold>;
 
+        /// Create a manifold from a signed distance function.
+        fn level_set(
+            ctx: &SdfContext,
+            bounds: &BoundingBox,
+            edge_length: f64,
+            level: f64,
+            tolerance: f64,
+        ) -> UniquePtr<Manifold>;
+
+        /// Vector of manifolds, used to pass several manifolds at once.
+        type ManifoldVec;
+
+        /// Create an empty vector of manifolds.
+        fn manifold_vec() -> UniquePtr<ManifoldVec>;
+
+        /// Append a manifold to the vector.
+        fn push(self: Pin<&mut ManifoldVec>, manifold: &Manifold);
+
+        /// Get the number of manifolds in the vector.
+        fn size(self: &ManifoldVec) -> usize;
+
+        /// Get a copy of a manifold in the vector.
+        fn get(self: &ManifoldVec, i: usize) -> UniquePtr<Manifold>;
+
+        /// Combine disjoint manifolds into one without boolean operation.
+        fn compose(manifolds: &ManifoldVec) -> UniquePtr<Manifold>;
+
+        /// Convex hull of all manifolds.
+        fn batch_hull(manifolds: &ManifoldVec) -> UniquePtr<Manifold>;
+
+        /// Convex hull of points given as flat x, y, z coordinates.
+        fn hull_points(points: &[f64]) -> UniquePtr<Manifold>;
+
+        /// Get the union of all manifolds.
+        fn batch_union(manifolds: &ManifoldVec) -> UniquePtr<Manifold>;
+
+        /// Get the intersection of all manifolds.
+        fn batch_intersection(manifolds: &ManifoldVec) -> UniquePtr<Manifold>;
+
+        /// Subtract all other manifolds from the first one.
+        fn batch_difference(manifolds: &ManifoldVec) -> UniquePtr<Manifold>;
+
         /// Get the union of two manifolds.
         fn union_(a: &Manifold, b: &Manifold) -> UniquePtr<Manifold>;
 
@@ -60,6 +219,12 @@ mod ffi {
         /// Get the difference of two manifolds.
         fn difference(a: &Manifold, b: &Manifold) -> UniquePtr<Manifold>;
 
+        /// Get the Minkowski sum of two manifolds.
+        fn minkowski_sum(a: &Manifold, b: &Manifold) -> UniquePtr<Manifold>;
+
+        /// Get the Minkowski difference of two manifolds.
+        fn minkowski_difference(a: &Manifold, b: &Manifold) -> UniquePtr<Manifold>;
+
         /// Trim by a plane.
         fn trim_by_plane(
             self: &Manifold,
@@ -69,6 +234,21 @@ mod ffi {
             offset: f64,
         ) -> UniquePtr<Manifold>;
 
+        /// Split by another manifold into intersection and difference.
+        fn split(self: &Manifold, cutter: &Manifold) -> UniquePtr<ManifoldVec>;
+
+        /// Split by a plane into the parts above and below.
+        fn split_by_plane(
+            self: &Manifold,
+            x: f64,
+            y: f64,
+            z: f64,
+            offset: f64,
+        ) -> UniquePtr<ManifoldVec>;
+
+        /// Decompose into connected components.
+        fn decompose(self: &Manifold) -> UniquePtr<ManifoldVec>;
+
         /// Convex hull.
         fn hull(self: &Manifold) -> UniquePtr<Manifold>;
 
@@ -81,6 +261,34 @@ mod ffi {
         /// Rotate the manifold.
         fn rotate(self: &Manifold, x: f64, y: f64, z: f64) -> UniquePtr<Manifold>;
 
+        /// Apply an affine transformation given as 4x3 matrix in column-major order.
+        fn transform(self: &Manifold, matrix: &[f64]) -> UniquePtr<Manifold>;
+
+        /// Mirror the manifold over the plane with the given normal.
+        fn mirror(self: &Manifold, x: f64, y: f64, z: f64) -> UniquePtr<Manifold>;
+
+        /// Move each vertex by calling a Rust closure.
+        fn warp(self: &Manifold, ctx: &mut WarpContext) -> UniquePtr<Manifold>;
+
+        /// Move all vertices by calling a Rust closure once.
+        fn warp_batch(self: &Manifold, ctx: &mut WarpBatchContext) -> UniquePtr<Manifold>;
+
+        /// Mark the manifold as original, assigning a new original ID.
+        fn as_original(self: &Manifold) -> UniquePtr<Manifold>;
+
+        /// Get the original ID, or -1 if the manifold is not an original.
+        fn original_id(self: &Manifold) -> i32;
+
+        /// Reserve original IDs and return the first one.
+        fn reserve_ids(n: u32) -> u32;
+
+        /// Replace the vertex properties by calling a Rust closure for each vertex.
+        fn set_properties(
+            self: &Manifold,
+            num_prop: usize,
+            ctx: &mut PropertiesContext,
+        ) -> UniquePtr<Manifold>;
+
         /// Extrude a polygon to create a manifold.
         fn extrude(
             multi_polygon_data: &[&[f64]],
@@ -91,6 +299,23 @@ mod ffi {
             scale_top_y: f64,
         ) -> UniquePtr<Manifold>;
 
+        /// Extrude polygons to create a manifold.
+        fn extrude_polygons(
+            polygons: &Polygons,
+            height: f64,
+            n_divisions: u32,
+            twist_degrees: f64,
+            scale_top_x: f64,
+            scale_top_y: f64,
+        ) -> UniquePtr<Manifold>;
+
+        /// Revolve polygons to create a manifold.
+        fn revolve_polygons(
+            polygons: &Polygons,
+            circular_segments: u32,
+            revolve_degrees: f64,
+        ) -> UniquePtr<Manifold>;
+
         /// Revolve a polygon to create a manifold.
         fn revolve(
             multi_polygon_data: &[&[f64]],
@@ -124,33 +349,175 @@ mod ffi {
             min_sharp_angle: f64,
         ) -> UniquePtr<Manifold>;
 
+        /// Create polygons from flat x, y coordinates.
+        fn polygons_from_data(multi_polygon_data: &[&[f64]]) -> UniquePtr<Polygons>;
+
         /// Manifold object, wrapper for C++ mesh object.
         type Mesh;
 
         /// Get the number of vertex properties of a mesh.
         fn num_props(self: &Mesh) -> u32;
 
-        /// Get the vertices of the mesh.
-        fn vertices(self: &Mesh) -> UniquePtr<CxxVector<f32>>;
+        /// Copy the mesh.
+        fn copy(self: &Mesh) -> UniquePtr<Mesh>;
+
+        /// Get the number of vertices of a mesh.
+        fn num_vert(self: &Mesh) -> usize;
+
+        /// Get the number of triangles of a mesh.
+        fn num_tri(self: &Mesh) -> usize;
 
-        /// Get the indices of the mesh.
-        fn indices(self: &Mesh) -> UniquePtr<CxxVector<u32>>;
+        /// Get the start index into the indices of each run.
+        fn run_index(self: &Mesh) -> &[u32];
 
-        /// Create a mesh from a manifold.
-        fn mesh_from_manifold(manifold: &Manifold) -> UniquePtr<Mesh>;
+        /// Get the original ID of each run.
+        fn run_original_id(self: &Mesh) -> &[u32];
+
+        /// Get the 4x3 column-major transform of each run.
+        fn run_transform(self: &Mesh) -> &[f32];
+
+        /// Get the source face ID of each triangle.
+        fn face_id(self: &Mesh) -> &[u32];
+
+        /// Get the vertices merged into other vertices.
+        fn merge_from_vert(self: &Mesh) -> &[u32];
+
+        /// Get the vertices the merged vertices are merged into.
+        fn merge_to_vert(self: &Mesh) -> &[u32];
+
+        /// Replace the merge vectors.
+        fn set_merge(self: Pin<&mut Mesh>, merge_from_vert: &[u32], merge_to_vert: &[u32]);
+
+        /// Merge vertices along open edges, return if anything changed.
+        fn merge(self: Pin<&mut Mesh>) -> bool;
+
+        /// View the vertex properties of the mesh.
+        fn vertex_slice(self: &Mesh) -> &[f32];
+
+        /// View the indices of the mesh.
+        fn index_slice(self: &Mesh) -> &[u32];
+
+        /// Create a mesh from a manifold, updating the normals at `normal_idx` unless it is -1.
+        fn mesh_from_manifold(manifold: &Manifold, normal_idx: i32) -> UniquePtr<Mesh>;
 
         /// Create a manifold from a mesh.
         fn manifold_from_mesh(mesh: &Mesh) -> UniquePtr<Manifold>;
 
         /// Create a mesh from vertices and indices.
         ///
-        /// The vertices are a flat array of floats containing the x, y, z coordinates of each vertex.
+        /// The vertices are a flat array of floats containing `num_prop` properties of each
+        /// vertex, starting with the x, y, z coordinates.
         /// The indices are a flat array of unsigned integers containing the indices of the vertices.
-        fn mesh_from_vertices(vertices: &[f32], indices: &[u32]) -> UniquePtr<Mesh>;
+        fn mesh_from_vertices(num_prop: u32, vertices: &[f32], indices: &[u32]) -> UniquePtr<Mesh>;
+
+        /// Double precision mesh with 64 bit indices, wrapper for C++ MeshGL64 object.
+        type Mesh64;
+
+        /// Get the number of vertex properties of a mesh.
+        fn num_props(self: &Mesh64) -> u32;
+
+        /// Copy the mesh.
+        fn copy(self: &Mesh64) -> UniquePtr<Mesh64>;
+
+        /// Get the number of vertices of a mesh.
+        fn num_vert(self: &Mesh64) -> usize;
+
+        /// Get the number of triangles of a mesh.
+        fn num_tri(self: &Mesh64) -> usize;
+
+        /// View the vertex properties of the mesh.
+        fn vertex_slice(self: &Mesh64) -> &[f64];
+
+        /// View the indices of the mesh.
+        fn index_slice(self: &Mesh64) -> &[u64];
+
+        /// Create a double precision mesh from a manifold, updating the normals at
+        /// `normal_idx` unless it is -1.
+        fn mesh64_from_manifold(manifold: &Manifold, normal_idx: i32) -> UniquePtr<Mesh64>;
+
+        /// Create a manifold from a double precision mesh.
+        fn manifold_from_mesh64(mesh: &Mesh64) -> UniquePtr<Manifold>;
+
+        /// Create a double precision mesh from vertices and indices.
+        fn mesh64_from_vertices(
+            num_prop: u32,
+            vertices: &[f64],
+            indices: &[u64],
+        ) -> UniquePtr<Mesh64>;
+
+        /// Cross section object, wrapper for C++ cross section object.
+        type CrossSection;
+
+        /// Does the cross section contain no contours?
+        fn is_empty(self: &CrossSection) -> bool;
+
+        /// Get the area of the cross section.
+        fn area(self: &CrossSection) -> f64;
+
+        /// Get the number of vertices.
+        fn num_vert(self: &CrossSection) -> usize;
+
+        /// Get the number of contours.
+        fn num_contour(self: &CrossSection) -> usize;
+
+        /// Translate the cross section.
+        fn translate(self: &CrossSection, x: f64, y: f64) -> UniquePtr<CrossSection>;
+
+        /// Rotate the cross section.
+        fn rotate(self: &CrossSection, degrees: f64) -> UniquePtr<CrossSection>;
+
+        /// Scale the cross section.
+        fn scale(self: &CrossSection, x: f64, y: f64) -> UniquePtr<CrossSection>;
+
+        /// Mirror the cross section.
+        fn mirror(self: &CrossSection, x: f64, y: f64) -> UniquePtr<CrossSection>;
+
+        /// Offset the contours of the cross section.
+        fn offset(
+            self: &CrossSection,
+            delta: f64,
+            join_type: u8,
+            miter_limit: f64,
+            circular_segments: i32,
+        ) -> UniquePtr<CrossSection>;
+
+        /// Convex hull.
+        fn hull(self: &CrossSection) -> UniquePtr<CrossSection>;
+
+        /// Get the contours as polygons.
+        fn to_polygons(self: &CrossSection) -> UniquePtr<Polygons>;
+
+        /// Create an empty cross section.
+        fn cross_section_empty() -> UniquePtr<CrossSection>;
+
+        /// Create a rectangle.
+        fn cross_section_square(x_size: f64, y_size: f64, center: bool) -> UniquePtr<CrossSection>;
+
+        /// Create a circle.
+        fn cross_section_circle(radius: f64, circular_segments: i32) -> UniquePtr<CrossSection>;
+
+        /// Create a cross section from polygons.
+        fn cross_section_from_polygons(
+            polygons: &Polygons,
+            fill_rule: u8,
+        ) -> UniquePtr<CrossSection>;
+
+        /// Get the union of two cross sections.
+        fn cross_section_union(a: &CrossSection, b: &CrossSection) -> UniquePtr<CrossSection>;
+
+        /// Get the intersection of two cross sections.
+        fn cross_section_intersection(
+            a: &CrossSection,
+            b: &CrossSection,
+        ) -> UniquePtr<CrossSection>;
+
+        /// Get the difference of two cross sections.
+        fn cross_section_difference(a: &CrossSection, b: &CrossSection) -> UniquePtr<CrossSection>;
     }
 }
 
 /// Boolean operation on manifolds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum BooleanOp {
     /// Union of two manifolds.
     Union,
@@ -160,50 +527,236 @@ pub enum BooleanOp {
     Difference,
 }
 
-/// Manifold rust wrapper for C++ polygons object.
-pub struct Polygons(cxx::UniquePtr<ffi::Polygons>);
-
-impl Polygons {
-    /// Get the number of polygons.
-    pub fn size(&self) -> usize {
-        self.0.size()
-    }
-
-    /// Get the number of vertices in a polygon.
-    pub fn get_as_slice(&self, i: usize) -> &[f64] {
-        self.0.get_as_slice(i)
-    }
-}
-
 /// Manifold rust wrapper for C++ manifold object.
 pub struct Manifold(cxx::UniquePtr<ffi::Manifold>);
 
+// SAFETY: The CSG tree behind a C++ manifold is evaluated lazily, which mutates the manifold
+// and tree nodes shared with other manifolds without any locking upstream. The bridge holds
+// a global lock in every function which accesses an existing manifold, so neither moving nor
+// sharing manifolds between threads can race.
+unsafe impl Send for Manifold {}
+unsafe impl Sync for Manifold {}
+
 impl Manifold {
     /// Does [`Manifold`] contain triangles?
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 
+    /// Get the error status of the manifold.
+    ///
+    /// Returns `Ok(())` if the manifold is valid.
+    pub fn status(&self) -> Result<(), ManifoldError> {
+        ManifoldError::from_status(self.0.status())
+    }
+
+    /// Get the volume of the manifold.
+    pub fn volume(&self) -> f64 {
+        self.0.volume()
+    }
+
+    /// Get the surface area of the manifold.
+    pub fn surface_area(&self) -> f64 {
+        self.0.surface_area()
+    }
+
+    /// Get the genus of the manifold, i.e. the number of handles.
+    ///
+    /// A sphere has genus 0, a torus genus 1. The genus is negative if the manifold
+    /// consists of several disjoint bodies.
+    pub fn genus(&self) -> i32 {
+        self.0.genus()
+    }
+
+    /// Get the number of vertices.
+    pub fn num_vert(&self) -> usize {
+        self.0.num_vert()
+    }
+
+    /// Get the number of edges.
+    pub fn num_edge(&self) -> usize {
+        self.0.num_edge()
+    }
+
+    /// Get the number of triangles.
+    pub fn num_tri(&self) -> usize {
+        self.0.num_tri()
+    }
+
+    /// Get the number of vertex properties, excluding the position.
+    pub fn num_prop(&self) -> usize {
+        self.0.num_prop()
+    }
+
+    /// Get the axis-aligned bounding box of the manifold.
+    pub fn bounding_box(&self) -> BoundingBox {
+        self.0.bounding_box()
+    }
+
+    /// Get the tolerance of the manifold.
+    ///
+    /// Geometric features smaller than the tolerance may be removed by operations.
+    pub fn tolerance(&self) -> f64 {
+        self.0.tolerance()
+    }
+
+    /// Get the minimum distance between the surfaces of two manifolds.
+    ///
+    /// Gaps larger than `search_length` are not detected and `search_length` is returned.
+    pub fn min_gap(&self, other: &Self, search_length: f64) -> f64 {
+        self.0.min_gap(other.inner(), search_length)
+    }
+
+    /// Do the two manifolds overlap in a non-zero volume?
+    ///
+    /// Disjoint bounding boxes are rejected before the intersection is computed.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.bounding_box().intersects(&other.bounding_box())
+            && !self.intersection(other).is_empty()
+    }
+
     /// Slice the manifold into a set of polygons.
     pub fn slice(&self, height: f64) -> Polygons {
         Polygons(self.0.slice(height))
     }
 
+    /// Slice the manifold into evenly spaced layers.
+    ///
+    /// Returns the height and the polygons of each layer from `z_start` up to and including
+    /// `z_end`, or no layers if `z_end` is below `z_start`. The result is the same as calling
+    /// [`Manifold::slice`] for each height, except that pending operations are evaluated once.
+    ///
+    /// Fails if `layer_height` is not finite and positive, or if the range is not finite or
+    /// has too many layers to allocate.
+    pub fn slice_layers(
+        &self,
+        z_start: f64,
+        z_end: f64,
+        layer_height: f64,
+    ) -> Result<Vec<(f64, Polygons)>, InputError> {
+        self.slice_layers_impl(z_start, z_end, layer_height, false)
+    }
+
+    /// Slice the manifold into evenly spaced layers using all CPU cores.
+    ///
+    /// Same as [`Manifold::slice_layers`], but the layers are computed in parallel.
+    pub fn slice_layers_parallel(
+        &self,
+        z_start: f64,
+        z_end: f64,
+        layer_height: f64,
+    ) -> Result<Vec<(f64, Polygons)>, InputError> {
+        self.slice_layers_impl(z_start, z_end, layer_height, true)
+    }
+
+    fn slice_layers_impl(
+        &self,
+        z_start: f64,
+        z_end: f64,
+        layer_height: f64,
+        parallel: bool,
+    ) -> Result<Vec<(f64, Polygons)>, InputError> {
+        if !layer_height.is_finite() || layer_height <= 0.0 {
+            return Err(InputError::InvalidLayerHeight);
+        }
+        if !z_start.is_finite() || !z_end.is_finite() {
+            return Err(InputError::InvalidLayerRange);
+        }
+
+        // Tolerate rounding so that `z_end` is included
+        let last = ((z_end - z_start) / layer_height + 1e-9).floor();
+        // The layer count may be infinite or exceed the maximum allocation size
+        let max_layers = (isize::MAX as usize / std::mem::size_of::<f64>()) as f64;
+        if last >= max_layers {
+            return Err(InputError::InvalidLayerRange);
+        }
+        let count = if last < 0.0 { 0 } else { last as usize + 1 };
+        let heights = (0..count)
+            .map(|i| z_start + i as f64 * layer_height)
+            .collect::<Vec<_>>();
+
+        let mut layers = self.0.slice_layers(&heights, parallel);
+        debug_assert_eq!(layers.size(), heights.len());
+        Ok(heights
+            .iter()
+            .enumerate()
+            .map(|(i, z)| (*z, Polygons(layers.pin_mut().take(i))))
+            .collect())
+    }
+
     /// Project the manifold onto a plane and return the resulting polygons.
     pub fn project(&self) -> Polygons {
         Polygons(self.0.project())
     }
 
+    /// Slice the manifold with the plane at signed distance `offset` from the origin along `normal`.
+    ///
+    /// Returns the polygons in 2D coordinates of the returned frame.
+    pub fn slice_plane(
+        &self,
+        normal: [f64; 3],
+        offset: f64,
+    ) -> Result<(Polygons, PlaneFrame), InputError> {
+        let frame = PlaneFrame::new(normal, offset)?;
+        let polygons = self.transform(frame.to_local()).slice(offset);
+        Ok((polygons, frame))
+    }
+
+    /// Project the manifold along `direction` onto the plane through the origin perpendicular to it.
+    ///
+    /// Returns the polygons in 2D coordinates of the returned frame.
+    pub fn project_along(&self, direction: [f64; 3]) -> Result<(Polygons, PlaneFrame), InputError> {
+        let frame = PlaneFrame::new(direction, 0.0)?;
+        let polygons = self.transform(frame.to_local()).project();
+        Ok((polygons, frame))
+    }
+
     /// Trim by a plane.
     pub fn trim_by_plane(&self, x: f64, y: f64, z: f64, offset: f64) -> Self {
         Self(self.0.trim_by_plane(x, y, z, offset))
     }
 
+    /// Split by another manifold.
+    ///
+    /// Returns the intersection with and the difference from the cutter.
+    pub fn split(&self, cutter: &Self) -> (Self, Self) {
+        manifold_pair(self.0.split(cutter.inner()))
+    }
+
+    /// Split by a plane with normal `(x, y, z)` and `offset` from the origin.
+    ///
+    /// Returns the part in direction of the normal and the part opposite to it.
+    pub fn split_by_plane(&self, x: f64, y: f64, z: f64, offset: f64) -> (Self, Self) {
+        manifold_pair(self.0.split_by_plane(x, y, z, offset))
+    }
+
+    /// Decompose into topologically disconnected manifolds.
+    pub fn decompose(&self) -> Vec<Self> {
+        manifold_vec_to_vec(&self.0.decompose())
+    }
+
+    /// Combine disjoint manifolds into one without a boolean operation.
+    ///
+    /// This is the inverse of [`Manifold::decompose`]. The manifolds must not overlap.
+    pub fn compose(manifolds: &[Self]) -> Self {
+        Self(ffi::compose(&manifold_vec(manifolds)))
+    }
+
     /// Convex hull.
     pub fn hull(&self) -> Self {
         Self(self.0.hull())
     }
 
+    /// Convex hull of a set of points.
+    pub fn hull_points(points: &[[f64; 3]]) -> Self {
+        Self(ffi::hull_points(points.as_flattened()))
+    }
+
+    /// Convex hull of several manifolds.
+    pub fn batch_hull(manifolds: &[&Self]) -> Self {
+        Self(ffi::batch_hull(&manifold_vec(manifolds.iter().copied())))
+    }
+
     /// Translate the manifold.
     pub fn translate(&self, x: f64, y: f64, z: f64) -> Self {
         Self(self.0.translate(x, y, z))
@@ -219,6 +772,75 @@ impl Manifold {
         Self(self.0.rotate(x, y, z))
     }
 
+    /// Apply an affine transformation.
+    ///
+    /// Accepts a [`Transform`] or anything convertible into one, e.g. a `[[f64; 3]; 4]` matrix
+    /// given as four columns where the last column is the translation.
+    pub fn transform(&self, matrix: impl Into<Transform>) -> Self {
+        Self(self.0.transform(matrix.into().0.as_flattened()))
+    }
+
+    /// Mirror the manifold over the plane through the origin with the normal `(x, y, z)`.
+    pub fn mirror(&self, x: f64, y: f64, z: f64) -> Self {
+        Self(self.0.mirror(x, y, z))
+    }
+
+    /// Deform the manifold by moving each vertex with a closure.
+    ///
+    /// The closure receives the vertex position and modifies it in place.
+    /// The topology stays the same, the result is checked for validity.
+    pub fn warp(&self, mut f: impl FnMut(&mut [f64; 3])) -> Result<Self, ManifoldError> {
+        let manifold = Self(self.0.warp(&mut WarpContext(&mut f)));
+        manifold.status()?;
+        Ok(manifold)
+    }
+
+    /// Deform the manifold by moving all vertices at once with a closure.
+    ///
+    /// Like [`Manifold::warp`], but the closure is called only once with all vertex positions.
+    pub fn warp_batch(&self, mut f: impl FnMut(&mut [[f64; 3]])) -> Result<Self, ManifoldError> {
+        let manifold = Self(self.0.warp_batch(&mut WarpBatchContext(&mut f)));
+        manifold.status()?;
+        Ok(manifold)
+    }
+
+    /// Mark the manifold as an original with a new unique ID.
+    ///
+    /// Triangles of the result of operations with this manifold can be traced back to it
+    /// through [`Mesh::runs`]. Coplanar triangles are grouped into faces, see [`Mesh::face_id`].
+    pub fn as_original(&self) -> Self {
+        Self(self.0.as_original())
+    }
+
+    /// Get the ID of the manifold if it is an original.
+    ///
+    /// Results of operations combining several manifolds are not originals.
+    pub fn original_id(&self) -> Option<u32> {
+        u32::try_from(self.0.original_id()).ok()
+    }
+
+    /// Reserve `n` original IDs which are not used by [`Manifold::as_original`] and
+    /// return the first one.
+    pub fn reserve_ids(n: u32) -> u32 {
+        ffi::reserve_ids(n)
+    }
+
+    /// Replace the vertex properties, keeping the positions.
+    ///
+    /// The closure is called for each vertex with a slice of `num_prop` new properties to fill,
+    /// the position and the old properties. The positions are not counted as properties here.
+    /// Properties are interpolated through boolean operations.
+    pub fn set_properties(
+        &self,
+        num_prop: usize,
+        mut f: impl FnMut(&mut [f64], [f64; 3], &[f64]),
+    ) -> Self {
+        Self(
+            self.0
+                .set_properties(num_prop, &mut PropertiesContext(&mut f)),
+        )
+    }
+
     /// Create empty manifold.
     pub fn empty() -> Self {
         Self(ffi::empty())
@@ -244,6 +866,36 @@ impl Manifold {
         Self(ffi::cylinder(radius_low, radius_high, height, segments))
     }
 
+    /// Create a manifold from a signed distance function.
+    ///
+    /// The function is sampled on a grid with spacing `edge_length` inside `bounds` and is
+    /// positive inside the shape. The surface is placed where the function equals `level`.
+    /// A negative `tolerance` selects a default.
+    /// The function may be called from several threads at once.
+    pub fn from_sdf(
+        sdf: impl Fn([f64; 3]) -> f64 + Sync,
+        bounds: BoundingBox,
+        edge_length: f64,
+        level: f64,
+        tolerance: f64,
+    ) -> Result<Self, InputError> {
+        if !(edge_length.is_finite() && edge_length > 0.0) {
+            return Err(InputError::InvalidEdgeLength);
+        }
+        if !(0..3).all(|i| {
+            bounds.min[i].is_finite() && bounds.max[i].is_finite() && bounds.min[i] <= bounds.max[i]
+        }) {
+            return Err(InputError::InvalidBounds);
+        }
+        Ok(Self(ffi::level_set(
+            &SdfContext(&sdf),
+            &bounds,
+            edge_length,
+            level,
+            tolerance,
+        )))
+    }
+
     /// Get the union of two manifolds.
     pub fn union(&self, b: &Self) -> Self {
         Self(ffi::union_(self.inner(), b.inner()))
@@ -259,6 +911,31 @@ impl Manifold {
         Self(ffi::difference(self.inner(), b.inner()))
     }
 
+    /// Get the Minkowski sum of two manifolds.
+    ///
+    /// The result contains all points `a + b` with `a` in `self` and `b` in `other`,
+    /// e.g. the sum with a sphere rounds and grows a shape by the radius.
+    ///
+    /// Without upstream support, non-convex operands are split into convex parts at the
+    /// planes of their concave faces, and the hulls of all pairs of parts are united.
+    /// The cost grows with the product of the numbers of parts, which is small for
+    /// machined parts but large for finely tessellated concave surfaces.
+    pub fn minkowski_sum(&self, other: &Self) -> Self {
+        Self(ffi::minkowski_sum(self.inner(), other.inner()))
+    }
+
+    /// Get the Minkowski difference of two manifolds.
+    ///
+    /// The result contains all points `p` for which `other` translated by `p` lies
+    /// within `self`, e.g. the difference with a sphere shrinks a shape by the radius.
+    ///
+    /// Without upstream support, this is computed from the Minkowski sum of the
+    /// complement of `self` within its bounding box, which is non-convex, so the cost
+    /// is like that of [`Manifold::minkowski_sum`] with non-convex operands.
+    pub fn minkowski_difference(&self, other: &Self) -> Self {
+        Self(ffi::minkowski_difference(self.inner(), other.inner()))
+    }
+
     /// Boolean operation on manifolds.
     pub fn boolean_op(&self, b: &Self, op: crate::BooleanOp) -> Self {
         match op {
@@ -268,7 +945,32 @@ impl Manifold {
         }
     }
 
+    /// Boolean operation on many manifolds at once.
+    ///
+    /// This is much faster than applying [`Manifold::boolean_op`] pairwise.
+    /// For [`BooleanOp::Difference`] all other manifolds are subtracted from the first one.
+    pub fn batch_boolean(manifolds: &[&Self], op: crate::BooleanOp) -> Self {
+        let manifolds = manifold_vec(manifolds.iter().copied());
+        Self(match op {
+            crate::BooleanOp::Union => ffi::batch_union(&manifolds),
+            crate::BooleanOp::Intersection => ffi::batch_intersection(&manifolds),
+            crate::BooleanOp::Difference => ffi::batch_difference(&manifolds),
+        })
+    }
+
+    /// Get the union of all manifolds.
+    pub fn union_all<'a>(manifolds: impl IntoIterator<Item = &'a Self>) -> Self {
+        Self(ffi::batch_union(&manifold_vec(manifolds)))
+    }
+
+    /// Get the intersection of all manifolds.
+    pub fn intersection_all<'a>(manifolds: impl IntoIterator<Item = &'a Self>) -> Self {
+        Self(ffi::batch_intersection(&manifold_vec(manifolds)))
+    }
+
     /// Extrude a polygon to create a manifold.
+    ///
+    /// Each polygon is a flat list of x, y coordinates.
     pub fn extrude(
         multi_polygon_data: &[&[f64]],
         height: f64,
@@ -276,28 +978,70 @@ impl Manifold {
         twist_degrees: f64,
         scale_top_x: f64,
         scale_top_y: f64,
-    ) -> Self {
-        Self(ffi::extrude(
+    ) -> Result<Self, InputError> {
+        validate_polygons(multi_polygon_data)?;
+        Ok(Self(ffi::extrude(
             multi_polygon_data,
             height,
             n_divisions,
             twist_degrees,
             scale_top_x,
             scale_top_y,
-        ))
+        )))
     }
 
     /// Revolve a polygon to create a manifold.
+    ///
+    /// Each polygon is a flat list of x, y coordinates.
     pub fn revolve(
         multi_polygon_data: &[&[f64]],
         circular_segments: u32,
         revolve_degrees: f64,
-    ) -> Self {
-        Self(ffi::revolve(
+    ) -> Result<Self, InputError> {
+        validate_polygons(multi_polygon_data)?;
+        Ok(Self(ffi::revolve(
             multi_polygon_data,
             circular_segments,
             revolve_degrees,
-        ))
+        )))
+    }
+
+    /// Extrude a 2D shape along the z-axis to create a manifold.
+    ///
+    /// Unlike [`Manifold::extrude`], the winding order of the contours does not matter
+    /// and intersecting contours are reported as error.
+    pub fn extrude_shape(
+        shape: &impl Shape2D,
+        height: f64,
+        n_divisions: u32,
+        twist_degrees: f64,
+        scale_top_x: f64,
+        scale_top_y: f64,
+    ) -> Result<Self, InputError> {
+        Ok(Self(ffi::extrude_polygons(
+            &shape.oriented_polygons()?.0,
+            height,
+            n_divisions,
+            twist_degrees,
+            scale_top_x,
+            scale_top_y,
+        )))
+    }
+
+    /// Revolve a 2D shape around the y-axis to create a manifold.
+    ///
+    /// Unlike [`Manifold::revolve`], the winding order of the contours does not matter
+    /// and intersecting contours are reported as error.
+    pub fn revolve_shape(
+        shape: &impl Shape2D,
+        circular_segments: u32,
+        revolve_degrees: f64,
+    ) -> Result<Self, InputError> {
+        Ok(Self(ffi::revolve_polygons(
+            &shape.oriented_polygons()?.0,
+            circular_segments,
+            revolve_degrees,
+        )))
     }
 
     /// Refine manifold.
@@ -331,8 +1075,32 @@ impl Manifold {
     }
 
     /// Get the mesh representation of the manifold.
+    ///
+    /// Vertex properties are passed through unchanged, see [`Manifold::to_mesh_with_normals`]
+    /// for properties holding normals.
+    ///
+    /// Before version 0.7 this updated normals stored at property 0 when transforming,
+    /// use `to_mesh_with_normals(0)` for that behavior.
     pub fn to_mesh(&self) -> Mesh {
-        Mesh(ffi::mesh_from_manifold(&self.0))
+        Mesh(ffi::mesh_from_manifold(&self.0, -1))
+    }
+
+    /// Get the mesh representation of the manifold with normals at the properties
+    /// `normal_idx..normal_idx + 3`, as created by [`Manifold::calculate_normals`].
+    ///
+    /// The normals are updated according to the transformations and booleans applied
+    /// since the manifold was created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than `normal_idx + 3` properties.
+    pub fn to_mesh_with_normals(&self, normal_idx: usize) -> Mesh {
+        assert!(
+            normal_idx + 3 <= self.num_prop(),
+            "normals at {normal_idx} exceed the {} properties",
+            self.num_prop()
+        );
+        Mesh(ffi::mesh_from_manifold(&self.0, normal_idx as i32))
     }
 
     /// Create a manifold from a mesh.
@@ -340,40 +1108,248 @@ impl Manifold {
         mesh.into()
     }
 
+    /// Create a manifold from a mesh and check its status.
+    pub fn try_from_mesh(mesh: Mesh) -> Result<Self, ManifoldError> {
+        mesh.try_to_manifold()
+    }
+
+    /// Get the double precision mesh representation of the manifold.
+    pub fn to_mesh64(&self) -> Mesh64 {
+        Mesh64(ffi::mesh64_from_manifold(&self.0, -1))
+    }
+
+    /// Get the double precision mesh representation of the manifold with normals.
+    ///
+    /// Like [`Manifold::to_mesh_with_normals`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than `normal_idx + 3` properties.
+    pub fn to_mesh64_with_normals(&self, normal_idx: usize) -> Mesh64 {
+        assert!(
+            normal_idx + 3 <= self.num_prop(),
+            "normals at {normal_idx} exceed the {} properties",
+            self.num_prop()
+        );
+        Mesh64(ffi::mesh64_from_manifold(&self.0, normal_idx as i32))
+    }
+
+    /// Create a manifold from a double precision mesh.
+    pub fn from_mesh64(mesh: Mesh64) -> Self {
+        mesh.into()
+    }
+
+    /// Create a manifold from a double precision mesh and check its status.
+    pub fn try_from_mesh64(mesh: Mesh64) -> Result<Self, ManifoldError> {
+        mesh.try_to_manifold()
+    }
+
     /// Get the inner C++ manifold object.
     fn inner(&self) -> &ffi::Manifold {
         self.0.as_ref().unwrap()
     }
 }
 
+/// Cheap copy which shares the C++ CSG tree.
+impl Clone for Manifold {
+    fn clone(&self) -> Self {
+        Self(self.0.copy())
+    }
+}
+
+/// Collect manifolds into a C++ vector.
+fn manifold_vec<'a>(
+    manifolds: impl IntoIterator<Item = &'a Manifold>,
+) -> cxx::UniquePtr<ffi::ManifoldVec> {
+    let mut vec = ffi::manifold_vec();
+    manifolds
+        .into_iter()
+        .for_each(|manifold| vec.pin_mut().push(manifold.inner()));
+    vec
+}
+
+/// Convert a C++ vector into manifolds.
+fn manifold_vec_to_vec(manifolds: &ffi::ManifoldVec) -> Vec<Manifold> {
+    (0..manifolds.size())
+        .map(|i| Manifold(manifolds.get(i)))
+        .collect()
+}
+
+/// Convert a C++ vector of two manifolds into a pair.
+fn manifold_pair(manifolds: cxx::UniquePtr<ffi::ManifoldVec>) -> (Manifold, Manifold) {
+    (Manifold(manifolds.get(0)), Manifold(manifolds.get(1)))
+}
+
+/// Union of all manifolds.
+impl std::iter::Sum for Manifold {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.collect()
+    }
+}
+
+/// Union of all manifolds.
+impl<'a> std::iter::Sum<&'a Manifold> for Manifold {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        Self::union_all(iter)
+    }
+}
+
+/// Union of all manifolds.
+impl FromIterator<Manifold> for Manifold {
+    fn from_iter<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        Self::union_all(&iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
 /// Wrapper around a C++ mesh object.
 pub struct Mesh(cxx::UniquePtr<ffi::Mesh>);
 
+// SAFETY: A C++ mesh is plain data in standard vectors, it is only modified through `&mut self`.
+unsafe impl Send for Mesh {}
+unsafe impl Sync for Mesh {}
+
 /// Implementations for the Mesh struct.
 impl Mesh {
     /// Create a new mesh from vertices and indices.
-    pub fn new(vertices: &[f32], indices: &[u32]) -> Self {
-        let mesh = ffi::mesh_from_vertices(vertices, indices);
-        Self(mesh)
+    ///
+    /// The vertices are a flat list of x, y, z coordinates and the indices a flat list of
+    /// triangle corners.
+    pub fn new(vertices: &[f32], indices: &[u32]) -> Result<Self, InputError> {
+        Self::with_properties(3, vertices, indices)
     }
 
-    /// Number of properties per vertex
+    /// Create a new mesh with additional vertex properties, like normals, colors or UVs.
+    ///
+    /// Each vertex has `num_prop` properties, the first three are the x, y, z coordinates.
+    pub fn with_properties(
+        num_prop: u32,
+        vertices: &[f32],
+        indices: &[u32],
+    ) -> Result<Self, InputError> {
+        validate_mesh(num_prop as usize, vertices, indices)?;
+        let mesh = ffi::mesh_from_vertices(num_prop, vertices, indices);
+        Ok(Self(mesh))
+    }
+
+    /// Create a manifold from vertices and indices and check its status.
+    ///
+    /// Shorthand for `Mesh::new(vertices, indices)?.try_to_manifold()`.
+    pub fn try_new(vertices: &[f32], indices: &[u32]) -> Result<Manifold, ManifoldError> {
+        Self::new(vertices, indices)?.try_to_manifold()
+    }
+
+    /// Number of properties per vertex, including the x, y, z coordinates
     pub fn num_props(&self) -> u32 {
         self.0.num_props()
     }
 
-    /// Get the vertices of the mesh.
+    /// Number of vertices
+    pub fn num_vert(&self) -> usize {
+        self.0.num_vert()
+    }
+
+    /// Number of triangles
+    pub fn num_tri(&self) -> usize {
+        self.0.num_tri()
+    }
+
+    /// Start of each run as index into [`Mesh::indices`], possibly followed by the end of the last run.
+    pub fn run_index(&self) -> &[u32] {
+        self.0.run_index()
+    }
+
+    /// Original ID of each run.
+    pub fn run_original_id(&self) -> &[u32] {
+        self.0.run_original_id()
+    }
+
+    /// Transformation of each run as 12 floats, a column-major 4x3 matrix.
+    pub fn run_transform(&self) -> &[f32] {
+        self.0.run_transform()
+    }
+
+    /// Source face ID of each triangle.
+    pub fn face_id(&self) -> &[u32] {
+        self.0.face_id()
+    }
+
+    /// Vertices which are merged into [`Mesh::merge_to_vert`] to form a manifold.
+    pub fn merge_from_vert(&self) -> &[u32] {
+        self.0.merge_from_vert()
+    }
+
+    /// Vertices into which the [`Mesh::merge_from_vert`] are merged.
+    pub fn merge_to_vert(&self) -> &[u32] {
+        self.0.merge_to_vert()
+    }
+
+    /// Set the merge vectors.
+    ///
+    /// Vertices at the same position but with different properties, like at normal or UV
+    /// seams, are separate vertices. The merge vectors pair them up so that the mesh can be
+    /// converted into a manifold.
+    pub fn with_merge(
+        mut self,
+        merge_from_vert: &[u32],
+        merge_to_vert: &[u32],
+    ) -> Result<Self, InputError> {
+        validate_merge(self.num_vert(), merge_from_vert, merge_to_vert)?;
+        self.0.pin_mut().set_merge(merge_from_vert, merge_to_vert);
+        Ok(self)
+    }
+
+    /// Merge vertices along open edges within tolerance, e.g. of meshes loaded from STL files.
+    ///
+    /// Only the merge vectors are updated, returns `false` if the mesh is already manifold.
+    pub fn merge(&mut self) -> bool {
+        self.0.pin_mut().merge()
+    }
+
+    /// Iterate over the runs of triangles originating from the same original manifold.
+    pub fn runs(&self) -> MeshRuns<'_> {
+        MeshRuns {
+            run_index: self.run_index(),
+            run_original_id: self.run_original_id(),
+            run_transform: self.run_transform(),
+            num_tri: self.num_tri(),
+            range: 0..self.run_original_id().len(),
+        }
+    }
+
+    /// Get a copy of the vertices of the mesh.
+    ///
+    /// Each vertex has [`Mesh::num_props`] properties, the first three are the x, y, z coordinates.
     pub fn vertices(&self) -> Vec<f32> {
-        let vertices_binding = self.0.vertices();
-        let vertices = vertices_binding.as_ref().unwrap().as_slice();
-        vertices.to_vec()
+        self.vertex_slice().to_vec()
     }
 
-    /// Get the indices of the mesh.
+    /// Get a copy of the indices of the mesh.
     pub fn indices(&self) -> Vec<u32> {
-        let indices_binding = self.0.indices();
-        let indices = indices_binding.as_ref().unwrap().as_slice();
-        indices.to_vec()
+        self.index_slice().to_vec()
+    }
+
+    /// View the vertices of the mesh without copying.
+    ///
+    /// Each vertex has [`Mesh::num_props`] properties, the first three are the x, y, z coordinates.
+    pub fn vertex_slice(&self) -> &[f32] {
+        self.0.vertex_slice()
+    }
+
+    /// View the indices of the mesh without copying.
+    pub fn index_slice(&self) -> &[u32] {
+        self.0.index_slice()
+    }
+
+    /// Iterate over the vertex positions.
+    pub fn positions(&self) -> impl ExactSizeIterator<Item = [f32; 3]> + '_ {
+        self.vertex_slice()
+            .chunks_exact(self.num_props() as usize)
+            .map(|v| [v[0], v[1], v[2]])
+    }
+
+    /// View the indices as triangles.
+    pub fn triangles(&self) -> &[[u32; 3]] {
+        self.index_slice().as_chunks().0
     }
 
     /// Get the manifold representation of the mesh.
@@ -381,6 +1357,19 @@ impl Mesh {
         let manifold = ffi::manifold_from_mesh(&self.0);
         Manifold(manifold)
     }
+
+    /// Get the manifold representation of the mesh and check its status.
+    pub fn try_to_manifold(&self) -> Result<Manifold, ManifoldError> {
+        let manifold = self.to_manifold();
+        manifold.status()?;
+        Ok(manifold)
+    }
+}
+
+impl Clone for Mesh {
+    fn clone(&self) -> Self {
+        Self(self.0.copy())
+    }
 }
 
 /// Convert Mesh to Manifold struct
@@ -397,17 +1386,747 @@ impl From<Manifold> for Mesh {
     }
 }
 
+/// Wrapper around a C++ double precision mesh object with 64 bit indices.
+///
+/// Unlike [`Mesh`], converting to and from a manifold does not lose precision.
+pub struct Mesh64(cxx::UniquePtr<ffi::Mesh64>);
+
+// SAFETY: Plain data like `Mesh`, never modified after construction.
+unsafe impl Send for Mesh64 {}
+unsafe impl Sync for Mesh64 {}
+
+impl Mesh64 {
+    /// Create a new mesh from vertices and indices.
+    ///
+    /// The vertices are a flat list of x, y, z coordinates and the indices a flat list of
+    /// triangle corners.
+    pub fn new(vertices: &[f64], indices: &[u64]) -> Result<Self, InputError> {
+        Self::with_properties(3, vertices, indices)
+    }
+
+    /// Create a new mesh with additional vertex properties.
+    ///
+    /// Each vertex has `num_prop` properties, the first three are the x, y, z coordinates.
+    pub fn with_properties(
+        num_prop: u32,
+        vertices: &[f64],
+        indices: &[u64],
+    ) -> Result<Self, InputError> {
+        validate_mesh(num_prop as usize, vertices, indices)?;
+        let mesh = ffi::mesh64_from_vertices(num_prop, vertices, indices);
+        Ok(Self(mesh))
+    }
+
+    /// Number of properties per vertex, including the x, y, z coordinates
+    pub fn num_props(&self) -> u32 {
+        self.0.num_props()
+    }
+
+    /// Number of vertices
+    pub fn num_vert(&self) -> usize {
+        self.0.num_vert()
+    }
+
+    /// Number of triangles
+    pub fn num_tri(&self) -> usize {
+        self.0.num_tri()
+    }
+
+    /// Get the vertices of the mesh.
+    ///
+    /// Each vertex has [`Mesh64::num_props`] properties, the first three are the x, y, z coordinates.
+    pub fn vertices(&self) -> Vec<f64> {
+        self.vertex_slice().to_vec()
+    }
+
+    /// Get the indices of the mesh.
+    pub fn indices(&self) -> Vec<u64> {
+        self.index_slice().to_vec()
+    }
+
+    /// View the vertices of the mesh without copying.
+    pub fn vertex_slice(&self) -> &[f64] {
+        self.0.vertex_slice()
+    }
+
+    /// View the indices of the mesh without copying.
+    pub fn index_slice(&self) -> &[u64] {
+        self.0.index_slice()
+    }
+
+    /// Get the manifold representation of the mesh.
+    pub fn to_manifold(&self) -> Manifold {
+        Manifold(ffi::manifold_from_mesh64(&self.0))
+    }
+
+    /// Get the manifold representation of the mesh and check its status.
+    pub fn try_to_manifold(&self) -> Result<Manifold, ManifoldError> {
+        let manifold = self.to_manifold();
+        manifold.status()?;
+        Ok(manifold)
+    }
+}
+
+impl Clone for Mesh64 {
+    fn clone(&self) -> Self {
+        Self(self.0.copy())
+    }
+}
+
+/// Convert Mesh64 to Manifold struct
+impl From<Mesh64> for Manifold {
+    fn from(mesh: Mesh64) -> Self {
+        mesh.to_manifold()
+    }
+}
+
+/// Convert Manifold to Mesh64 struct
+impl From<Manifold> for Mesh64 {
+    fn from(manifold: Manifold) -> Self {
+        manifold.to_mesh64()
+    }
+}
+
 #[test]
 fn test_manifold_ffi() {
     let sphere = ffi::sphere(1.0, 32);
 
-    let mesh = ffi::mesh_from_manifold(&sphere);
+    let mesh = ffi::mesh_from_manifold(&sphere, -1);
 
-    let vertices_binding = mesh.vertices();
-    let vertices = vertices_binding.as_ref().unwrap().as_slice();
+    let vertices = mesh.vertex_slice();
     assert!(!vertices.is_empty());
 
-    let indices_binding = mesh.indices();
-    let indices = indices_binding.as_ref().unwrap().as_slice();
+    let indices = mesh.index_slice();
     assert!(!indices.is_empty());
 }
+
+#[test]
+fn test_manifold_status() {
+    let tetrahedron = Manifold::tetrahedron();
+    assert_eq!(tetrahedron.status(), Ok(()));
+
+    let mesh = tetrahedron.to_mesh();
+    let vertices = mesh.vertices();
+    let indices = mesh.indices();
+    assert!(Mesh::try_new(&vertices, &indices).is_ok());
+
+    // Removing a triangle leaves a hole
+    assert_eq!(
+        Mesh::try_new(&vertices, &indices[3..]).err(),
+        Some(ManifoldError::NotManifold)
+    );
+}
+
+#[test]
+fn test_self_intersection() {
+    let bowtie = [0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0, 1.0];
+    assert_eq!(
+        error::validate_simple_polygons(&[&bowtie]),
+        Err(InputError::SelfIntersection {
+            polygon: 0,
+            edge: 0,
+            other_polygon: 0,
+            other_edge: 2
+        })
+    );
+
+    let outer = [0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+    let inner = [1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 2.0, 1.0];
+    let crossing = [3.0, 1.0, 5.0, 1.0, 5.0, 2.0, 3.0, 2.0];
+    assert!(error::validate_simple_polygons(&[&outer, &inner]).is_ok());
+    assert_eq!(
+        error::validate_simple_polygons(&[&outer, &inner, &crossing]),
+        Err(InputError::SelfIntersection {
+            polygon: 0,
+            edge: 1,
+            other_polygon: 2,
+            other_edge: 0
+        })
+    );
+}
+
+#[test]
+fn test_input_validation() {
+    let vertices = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+    assert!(Mesh::new(&vertices, &[0, 1, 2]).is_ok());
+    assert_eq!(
+        Mesh::new(&vertices[..8], &[0, 1, 2]).err(),
+        Some(InputError::VerticesWrongLength {
+            len: 8,
+            num_prop: 3
+        })
+    );
+    assert_eq!(
+        Mesh::new(&vertices, &[0, 1, 2, 0, 2, 3]).err(),
+        Some(InputError::IndexOutOfBounds {
+            triangle: 1,
+            index: 3,
+            num_vert: 3
+        })
+    );
+
+    let square = [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
+    let broken = [0.0, 0.0, 1.0, f64::NAN, 1.0, 1.0];
+    assert!(Manifold::extrude(&[&square], 1.0, 0, 0.0, 1.0, 1.0).is_ok());
+    assert_eq!(
+        Manifold::extrude(&[&square, &square[..5]], 1.0, 0, 0.0, 1.0, 1.0).err(),
+        Some(InputError::PolygonWrongLength { polygon: 1, len: 5 })
+    );
+    assert_eq!(
+        Manifold::revolve(&[&square, &broken], 32, 360.0).err(),
+        Some(InputError::NonFinitePolygonVertex {
+            polygon: 1,
+            vertex: 1
+        })
+    );
+}
+
+#[test]
+fn test_transform() {
+    let tetrahedron = Manifold::tetrahedron();
+    let matrix = [
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0],
+        [1.0, 2.0, 3.0],
+    ];
+    assert_eq!(
+        tetrahedron.transform(matrix).to_mesh().vertices(),
+        tetrahedron.translate(1.0, 2.0, 3.0).to_mesh().vertices()
+    );
+
+    let mirrored = tetrahedron.mirror(1.0, 0.0, 0.0).to_mesh().vertices();
+    let scaled = tetrahedron.scale(-1.0, 1.0, 1.0).to_mesh().vertices();
+    assert_eq!(mirrored, scaled);
+}
+
+#[test]
+fn test_warp() {
+    let cube = Manifold::cube(1.0, 1.0, 1.0);
+    let translated = cube.translate(1.0, 2.0, 3.0).to_mesh().vertices();
+
+    let warped = cube
+        .warp(|p| {
+            p[0] += 1.0;
+            p[1] += 2.0;
+            p[2] += 3.0;
+        })
+        .unwrap();
+    assert_eq!(warped.to_mesh().vertices(), translated);
+
+    let warped = cube
+        .warp_batch(|points| {
+            points.iter_mut().for_each(|p| {
+                p[0] += 1.0;
+                p[1] += 2.0;
+                p[2] += 3.0;
+            })
+        })
+        .unwrap();
+    assert_eq!(warped.to_mesh().vertices(), translated);
+
+    let empty = Manifold::empty().warp_batch(|points| assert!(points.is_empty()));
+    assert!(empty.unwrap().is_empty());
+
+    assert_eq!(
+        cube.warp(|p| p[0] = f64::NAN).err(),
+        Some(ManifoldError::NonFiniteVertex)
+    );
+}
+
+#[test]
+fn test_from_sdf() {
+    let radius = 1.0;
+    let sphere = Manifold::from_sdf(
+        |[x, y, z]| radius - (x * x + y * y + z * z).sqrt(),
+        BoundingBox::new([-2.0; 3], [2.0; 3]),
+        0.1,
+        0.0,
+        -1.0,
+    )
+    .unwrap();
+    assert_eq!(sphere.status(), Ok(()));
+    assert!(!sphere.is_empty());
+
+    let vertices = sphere.to_mesh().vertices();
+    assert!(vertices.chunks(3).all(|v| {
+        let r = v.iter().map(|x| x * x).sum::<f32>().sqrt() as f64;
+        (r - radius).abs() < 0.02
+    }));
+
+    let sdf = |[x, _, _]: [f64; 3]| x;
+    let bounds = BoundingBox::new([-1.0; 3], [1.0; 3]);
+    assert_eq!(
+        Manifold::from_sdf(sdf, bounds, 0.0, 0.0, -1.0).err(),
+        Some(InputError::InvalidEdgeLength)
+    );
+    assert_eq!(
+        Manifold::from_sdf(sdf, bounds, f64::NAN, 0.0, -1.0).err(),
+        Some(InputError::InvalidEdgeLength)
+    );
+    assert_eq!(
+        Manifold::from_sdf(sdf, BoundingBox::new([1.0; 3], [-1.0; 3]), 0.1, 0.0, -1.0).err(),
+        Some(InputError::InvalidBounds)
+    );
+}
+
+#[test]
+fn test_queries() {
+    let cube = Manifold::cube(1.0, 2.0, 3.0);
+    assert!((cube.volume() - 6.0).abs() < 1e-9);
+    assert!((cube.surface_area() - 22.0).abs() < 1e-9);
+    assert_eq!(cube.genus(), 0);
+    assert_eq!(cube.num_vert(), 8);
+    assert_eq!(cube.num_edge(), 18);
+    assert_eq!(cube.num_tri(), 12);
+    assert_eq!(cube.num_prop(), 0);
+
+    let bounding_box = cube.bounding_box();
+    assert_eq!(bounding_box.min, [0.0, 0.0, 0.0]);
+    assert_eq!(bounding_box.max, [1.0, 2.0, 3.0]);
+    assert_eq!(bounding_box.center(), [0.5, 1.0, 1.5]);
+    assert_eq!(bounding_box.size(), [1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_min_gap() {
+    let a = Manifold::cube(1.0, 1.0, 1.0);
+    let b = Manifold::cube(1.0, 1.0, 1.0).translate(1.5, 0.0, 0.0);
+    let c = Manifold::cube(1.0, 1.0, 1.0).translate(0.5, 0.5, 0.5);
+
+    assert!((a.min_gap(&b, 1.0) - 0.5).abs() < 1e-9);
+    assert_eq!(a.min_gap(&b, 0.1), 0.1);
+    assert!(!a.intersects(&b));
+    assert!(a.intersects(&c));
+}
+
+#[test]
+fn test_batch_boolean() {
+    let cubes = (0..4)
+        .map(|i| Manifold::cube(1.0, 1.0, 1.0).translate(2.0 * i as f64, 0.0, 0.0))
+        .collect::<Vec<_>>();
+
+    assert!((Manifold::union_all(&cubes).volume() - 4.0).abs() < 1e-9);
+    assert!((cubes.iter().sum::<Manifold>().volume() - 4.0).abs() < 1e-9);
+
+    let block = Manifold::cube(8.0, 1.0, 1.0);
+    let refs = std::iter::once(&block).chain(&cubes).collect::<Vec<_>>();
+    let difference = Manifold::batch_boolean(&refs, BooleanOp::Difference);
+    assert!((difference.volume() - 4.0).abs() < 1e-9);
+    let intersection = Manifold::batch_boolean(&refs[..2], BooleanOp::Intersection);
+    assert!((intersection.volume() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_split_and_decompose() {
+    let cube = Manifold::cube(2.0, 2.0, 2.0);
+
+    let (top, bottom) = cube.split_by_plane(0.0, 0.0, 1.0, 0.5);
+    assert!((top.volume() - 6.0).abs() < 1e-9);
+    assert!((bottom.volume() - 2.0).abs() < 1e-9);
+
+    let (inside, outside) = cube.split(&Manifold::cube(1.0, 1.0, 1.0));
+    assert!((inside.volume() - 1.0).abs() < 1e-9);
+    assert!((outside.volume() - 7.0).abs() < 1e-9);
+
+    let parts = cube.union(&cube.translate(4.0, 0.0, 0.0)).decompose();
+    assert_eq!(parts.len(), 2);
+    assert!(parts.iter().all(|part| (part.volume() - 8.0).abs() < 1e-9));
+    assert!((Manifold::compose(&parts).volume() - 16.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_hull() {
+    let corners = (0..8)
+        .map(|i| [(i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64])
+        .collect::<Vec<_>>();
+    let points = [corners.as_slice(), &[[0.5, 0.5, 0.5]]].concat();
+    let hull = Manifold::hull_points(&points);
+    assert!((hull.volume() - 1.0).abs() < 1e-9);
+    assert_eq!(hull.num_vert(), 8);
+
+    let a = Manifold::cube(1.0, 1.0, 1.0);
+    let b = a.translate(2.0, 0.0, 0.0);
+    assert!((Manifold::batch_hull(&[&a, &b]).volume() - 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_minkowski() {
+    use std::f64::consts::PI;
+
+    // Cube grown by a sphere: V + A r + 3 pi a r² + 4/3 pi r³
+    let (a, r) = (2.0, 0.5);
+    let cube = Manifold::cube(a, a, a);
+    let sphere = Manifold::sphere(r, 64);
+    let expected = a * a * a + 6.0 * a * a * r + 3.0 * PI * a * r * r + 4.0 / 3.0 * PI * r * r * r;
+    let sum = cube.minkowski_sum(&sphere);
+    assert!((sum.volume() - expected).abs() / expected < 0.01);
+
+    // Cube shrunk by a sphere
+    let difference = cube.minkowski_difference(&sphere);
+    assert!((difference.volume() - (a - 2.0 * r).powi(3)).abs() < 0.01);
+
+    // Non-convex L-shape grown by a unit cube
+    let l_shape = Manifold::cube(2.0, 1.0, 1.0).union(&Manifold::cube(1.0, 2.0, 1.0));
+    let unit = Manifold::cube(1.0, 1.0, 1.0);
+    assert!((l_shape.minkowski_sum(&unit).volume() - 16.0).abs() < 1e-6);
+
+    // Non-convex L-shape grown by itself: area 13 in xy, height 2
+    assert!((l_shape.minkowski_sum(&l_shape).volume() - 26.0).abs() < 1e-6);
+
+    // Small parts are not mistaken for convex ones
+    let small_l_shape = l_shape.scale(0.01, 0.01, 0.01);
+    let small_sum = small_l_shape.minkowski_sum(&small_l_shape);
+    assert!((small_sum.volume() / 26.0e-6 - 1.0).abs() < 1e-6);
+
+    // L-shape shrunk by a small cube: two overlapping 1.5 x 0.5 strips, height 0.5
+    let small = Manifold::cube(0.5, 0.5, 0.5);
+    let shrunk = l_shape.minkowski_difference(&small);
+    assert!((shrunk.volume() - 1.25 * 0.5).abs() < 1e-6);
+
+    assert!(
+        (Manifold::cube(4.0, 4.0, 4.0)
+            .minkowski_difference(&unit)
+            .volume()
+            - 27.0)
+            .abs()
+            < 1e-6
+    );
+}
+
+#[test]
+fn test_extrude_shape() {
+    let frame =
+        CrossSection::square(2.0, 2.0, true).difference(&CrossSection::square(1.0, 1.0, true));
+    let extruded = Manifold::extrude_shape(&frame, 2.0, 0, 0.0, 1.0, 1.0).unwrap();
+    assert!((extruded.volume() - 6.0).abs() < 1e-9);
+
+    // Both contours clockwise, the inner one still becomes a hole
+    let outer = [-1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0, -1.0];
+    let inner = [-0.5, -0.5, -0.5, 0.5, 0.5, 0.5, 0.5, -0.5];
+    let polygons = Polygons(ffi::polygons_from_data(&[&outer, &inner]));
+    let extruded = Manifold::extrude_shape(&polygons, 2.0, 0, 0.0, 1.0, 1.0).unwrap();
+    assert!((extruded.volume() - 6.0).abs() < 1e-9);
+
+    let bowtie = [0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0, 1.0];
+    let polygons = Polygons(ffi::polygons_from_data(&[&bowtie]));
+    assert!(matches!(
+        Manifold::extrude_shape(&polygons, 2.0, 0, 0.0, 1.0, 1.0),
+        Err(InputError::SelfIntersection { .. })
+    ));
+
+    // Pappus: area times distance travelled by the centroid
+    let revolved = Manifold::revolve_shape(&frame.translate(2.0, 0.0), 64, 360.0).unwrap();
+    let expected = 3.0 * 2.0 * std::f64::consts::PI * 2.0;
+    assert!((revolved.volume() - expected).abs() / expected < 0.01);
+}
+
+#[test]
+fn test_slice_layers() {
+    let manifold = Manifold::sphere(1.0, 32).union(&Manifold::cube(1.0, 1.0, 1.0));
+    let layers = manifold.slice_layers(-0.95, 0.95, 0.1).unwrap();
+    assert_eq!(layers.len(), 20);
+    assert!(manifold.slice_layers(1.0, 0.0, 0.1).unwrap().is_empty());
+    assert_eq!(
+        manifold.slice_layers(0.0, 1.0, 0.0).unwrap_err(),
+        InputError::InvalidLayerHeight
+    );
+    assert_eq!(
+        manifold.slice_layers(0.0, f64::INFINITY, 0.1).unwrap_err(),
+        InputError::InvalidLayerRange
+    );
+    assert_eq!(
+        manifold.slice_layers(-1e300, 1e300, 1e-300).unwrap_err(),
+        InputError::InvalidLayerRange
+    );
+
+    let parallel = manifold.slice_layers_parallel(-0.95, 0.95, 0.1).unwrap();
+    for ((z, polygons), (z_parallel, polygons_parallel)) in layers.iter().zip(&parallel) {
+        assert_eq!(z, z_parallel);
+        let single = manifold.slice(*z);
+        assert_eq!(polygons.size(), single.size());
+        for i in 0..single.size() {
+            assert_eq!(polygons.get_as_slice(i), single.get_as_slice(i));
+            assert_eq!(polygons_parallel.get_as_slice(i), single.get_as_slice(i));
+        }
+    }
+}
+
+#[test]
+fn test_slice_plane() {
+    let manifold = Manifold::cube(1.0, 2.0, 3.0);
+
+    // The z normal gives the same result as slice
+    let (polygons, frame) = manifold.slice_plane([0.0, 0.0, 2.0], 1.5).unwrap();
+    assert_eq!(frame.origin, [0.0, 0.0, 1.5]);
+    assert_eq!(polygons.get(0), manifold.slice(1.5).get(0));
+
+    // Side view: cross section perpendicular to x is 2 x 3
+    let (polygons, frame) = manifold.slice_plane([1.0, 0.0, 0.0], 0.5).unwrap();
+    assert!((polygons.area() - 6.0).abs() < 1e-9);
+    for p in polygons.get(0) {
+        assert!((frame.to_3d(*p)[0] - 0.5).abs() < 1e-9);
+    }
+
+    let (polygons, _) = manifold.project_along([0.0, -1.0, 0.0]).unwrap();
+    assert!((polygons.area() - 3.0).abs() < 1e-9);
+
+    assert_eq!(
+        manifold.project_along([0.0; 3]).unwrap_err(),
+        InputError::InvalidDirection
+    );
+}
+
+#[test]
+fn test_properties() {
+    // Tetrahedron with a color per vertex
+    let vertices = [
+        [1.0, 1.0, 1.0, 1.0, 0.0, 0.0],
+        [-1.0, -1.0, 1.0, 0.0, 1.0, 0.0],
+        [-1.0, 1.0, -1.0, 0.0, 0.0, 1.0],
+        [1.0, -1.0, -1.0, 1.0, 1.0, 1.0],
+    ];
+    let indices = [2, 0, 1, 0, 3, 1, 2, 3, 0, 3, 2, 1];
+    let mesh = Mesh::with_properties(6, vertices.as_flattened(), &indices).unwrap();
+    assert_eq!(mesh.num_props(), 6);
+    assert_eq!(mesh.num_vert(), 4);
+    assert_eq!(mesh.to_manifold().num_prop(), 3);
+    assert_eq!(
+        Mesh::with_properties(2, &[0.0; 4], &[]).err(),
+        Some(InputError::MissingPositionProperties { num_prop: 2 })
+    );
+
+    // Store the height as property, it survives booleans
+    let cube = Manifold::cube(1.0, 1.0, 1.0).set_properties(1, |new, pos, old| {
+        assert!(old.is_empty());
+        new[0] = pos[2];
+    });
+    assert_eq!(cube.num_prop(), 1);
+    let sphere = Manifold::sphere(0.5, 16).set_properties(1, |new, pos, _| new[0] = pos[2]);
+    let result = cube.difference(&sphere);
+    assert_eq!(result.num_prop(), 1);
+
+    let mesh = result.to_mesh();
+    assert_eq!(mesh.num_props(), 4);
+    for v in mesh.vertices().chunks_exact(4) {
+        assert!((v[3] - v[2]).abs() < 1e-5);
+    }
+
+    // Colors are not mistaken for normals after transformations
+    let color = |new: &mut [f64], _, _: &[f64]| new.copy_from_slice(&[0.2, 0.4, 0.6]);
+    let colored = Manifold::cube(1.0, 1.0, 1.0)
+        .set_properties(3, color)
+        .rotate(90.0, 0.0, 0.0)
+        .difference(&Manifold::sphere(0.5, 16).set_properties(3, color));
+    for v in colored.to_mesh().vertices().chunks_exact(6) {
+        assert_eq!(v[3..], [0.2, 0.4, 0.6]);
+    }
+
+    // Normals are rotated along with the manifold
+    let rotated = Manifold::cube(1.0, 1.0, 1.0)
+        .calculate_normals(0, 60.0)
+        .rotate(0.0, 0.0, 45.0)
+        .difference(&Manifold::cube(0.5, 0.5, 2.0).calculate_normals(0, 60.0));
+    let mesh = rotated.to_mesh_with_normals(0);
+    for v in mesh.vertices().chunks_exact(6) {
+        let length = v[3..].iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!((length - 1.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_mesh_runs() {
+    let cube = Manifold::cube(1.0, 1.0, 1.0).as_original();
+    let sphere = Manifold::sphere(0.6, 16)
+        .translate(1.0, 1.0, 1.0)
+        .as_original();
+    let (cube_id, sphere_id) = (cube.original_id().unwrap(), sphere.original_id().unwrap());
+    assert_ne!(cube_id, sphere_id);
+
+    let result = cube.difference(&sphere.translate(0.0, 0.0, 0.5));
+    assert_eq!(result.original_id(), None);
+
+    let mesh = result.to_mesh();
+    assert_eq!(mesh.face_id().len(), mesh.num_tri());
+    let runs = mesh.runs().collect::<Vec<_>>();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(
+        runs.iter().map(|run| run.triangles.len()).sum::<usize>(),
+        mesh.num_tri()
+    );
+    let sphere_run = runs
+        .iter()
+        .find(|run| run.original_id == sphere_id)
+        .unwrap();
+    assert!(sphere_run.transform.0[3][2] > 0.0);
+    assert!(runs.iter().any(|run| run.original_id == cube_id));
+
+    let first = Manifold::reserve_ids(10);
+    assert!(
+        Manifold::cube(1.0, 1.0, 1.0)
+            .as_original()
+            .original_id()
+            .unwrap()
+            >= first + 10
+    );
+}
+
+#[test]
+fn test_merge() {
+    // Cube with separate vertices for each face, as loaded from STL
+    let mesh = Manifold::cube(1.0, 1.0, 1.0).to_mesh();
+    let vertices = mesh.vertices();
+    let indices = mesh.indices();
+    let mut vertices_split = Vec::new();
+    for i in &indices {
+        let i = *i as usize * 3;
+        vertices_split.extend_from_slice(&vertices[i..i + 3]);
+    }
+    let indices_split = (0..indices.len() as u32).collect::<Vec<_>>();
+
+    let mut mesh = Mesh::new(&vertices_split, &indices_split).unwrap();
+    assert_eq!(
+        mesh.try_to_manifold().err(),
+        Some(ManifoldError::NotManifold)
+    );
+    assert!(mesh.merge());
+    assert!(!mesh.merge_from_vert().is_empty());
+    assert_eq!(mesh.merge_from_vert().len(), mesh.merge_to_vert().len());
+    let manifold = mesh.try_to_manifold().unwrap();
+    assert!((manifold.volume() - 1.0).abs() < 1e-6);
+
+    // Merge vectors survive the round trip and can be passed in again
+    let mesh = Mesh::new(&vertices_split, &indices_split)
+        .unwrap()
+        .with_merge(mesh.merge_from_vert(), mesh.merge_to_vert())
+        .unwrap();
+    assert!(mesh.try_to_manifold().is_ok());
+    assert_eq!(
+        Mesh::new(&vertices_split, &indices_split)
+            .unwrap()
+            .with_merge(&[0], &[100])
+            .err(),
+        Some(InputError::MergeIndexOutOfBounds {
+            index: 100,
+            num_vert: 36
+        })
+    );
+}
+
+#[test]
+fn test_mesh64() {
+    // Small offset far from the origin, lost in single precision
+    let offset = 1.0e6 + 1.0e-3;
+    let cube = Manifold::cube(1.0, 1.0, 1.0).translate(offset, 0.0, 0.0);
+    let mesh = cube.to_mesh64();
+    assert_eq!(mesh.num_props(), 3);
+    assert_eq!(mesh.num_tri(), 12);
+    let min_x = |vertices: &[f64]| {
+        vertices
+            .chunks_exact(3)
+            .map(|v| v[0])
+            .fold(f64::INFINITY, f64::min)
+    };
+    assert_eq!(min_x(&mesh.vertices()), offset);
+    assert_ne!(
+        min_x(
+            &cube
+                .to_mesh()
+                .vertices()
+                .iter()
+                .map(|&x| x as f64)
+                .collect::<Vec<_>>()
+        ),
+        offset
+    );
+
+    let with_normals = cube.calculate_normals(0, 60.0).to_mesh64_with_normals(0);
+    assert_eq!(with_normals.num_props(), 6);
+
+    let mesh = Mesh64::new(&mesh.vertices(), &mesh.indices()).unwrap();
+    let round_trip = Manifold::try_from_mesh64(mesh).unwrap();
+    assert_eq!(min_x(&round_trip.to_mesh64().vertices()), offset);
+    assert_eq!(
+        Mesh64::new(&[0.0; 9], &[0, 1, 3]).err(),
+        Some(InputError::IndexOutOfBounds {
+            triangle: 0,
+            index: 3,
+            num_vert: 3
+        })
+    );
+}
+
+#[test]
+fn test_mesh_slices() {
+    let mesh = Manifold::cube(1.0, 2.0, 3.0)
+        .set_properties(2, |new, _, _| new.fill(0.5))
+        .to_mesh();
+    assert_eq!(mesh.vertex_slice(), mesh.vertices());
+    assert_eq!(mesh.index_slice(), mesh.indices());
+    assert_eq!(mesh.positions().len(), mesh.num_vert());
+    assert!(mesh.positions().any(|p| p == [1.0, 2.0, 3.0]));
+    assert_eq!(mesh.triangles().len(), mesh.num_tri());
+    assert_eq!(mesh.triangles().as_flattened(), mesh.index_slice());
+}
+
+#[test]
+fn test_threads() {
+    let a = Manifold::cube(1.0, 1.0, 1.0);
+    let b = Manifold::sphere(0.5, 24);
+    let expected = a.difference(&b).volume();
+
+    // Evaluate the same unevaluated manifold and its clones from many threads
+    let shared = a.difference(&b).translate(1.0, 0.0, 0.0);
+    let results = std::thread::scope(|scope| {
+        let threads = (0..16)
+            .map(|i| {
+                let shared = &shared;
+                let clone = shared.clone();
+                scope.spawn(move || {
+                    let manifold = if i % 2 == 0 { shared } else { &clone };
+                    (manifold.volume(), manifold.to_mesh(), manifold.slice(0.25))
+                })
+            })
+            .collect::<Vec<_>>();
+        threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    for (volume, mesh, polygons) in results {
+        assert!((volume - expected).abs() < 1e-6);
+        assert!(mesh.num_tri() > 0);
+        assert!(!polygons.is_empty());
+    }
+
+    // Unevaluated manifolds sharing nodes with the original can be moved to other threads
+    let moved = a.difference(&b).translate(1.0, 0.0, 0.0);
+    let moved = std::thread::spawn(move || moved.union(&Manifold::cube(1.0, 1.0, 1.0)).volume());
+    assert!((a.difference(&b).volume() - expected).abs() < 1e-6);
+    assert!((moved.join().unwrap() - expected - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_clone() {
+    let cube = Manifold::cube(1.0, 1.0, 1.0);
+    let clone = cube.clone();
+    let moved = clone.translate(2.0, 0.0, 0.0);
+    assert_eq!(cube.bounding_box(), clone.bounding_box());
+    assert_eq!(moved.bounding_box().min, [2.0, 0.0, 0.0]);
+    assert_eq!(cube.bounding_box().min, [0.0; 3]);
+
+    let scaled = cube.scale(2.0, 2.0, 2.0);
+    assert!((clone.volume() - 1.0).abs() < 1e-9);
+    assert!((scaled.volume() - 8.0).abs() < 1e-9);
+
+    let mesh = cube.to_mesh();
+    let mut mesh_clone = mesh.clone();
+    assert_eq!(mesh_clone.vertex_slice(), mesh.vertex_slice());
+    mesh_clone = mesh_clone.with_merge(&[0], &[1]).unwrap();
+    assert!(mesh.merge_from_vert().is_empty());
+    assert_eq!(mesh_clone.merge_from_vert(), [0]);
+
+    let mesh64 = cube.to_mesh64();
+    assert_eq!(mesh64.clone().vertex_slice(), mesh64.vertex_slice());
+}
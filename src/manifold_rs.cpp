@@ -2,12 +2,31 @@
 // SPDX-License-Identifier: Apache-2.0
 
 #include "manifold_rs.h"
+#include "manifold-rs/src/lib.rs.h"
 
 #include <manifold/manifold.h>
+#include <manifold/polygon.h>
+#include <algorithm>
 #include <cstring> // memcpy
+#include <mutex>
+#include <thread>
 
 namespace manifold_rs
 {
+    namespace
+    {
+        /// Lock access to existing manifolds
+        ///
+        /// Upstream evaluates the CSG tree lazily and caches the results in nodes which are
+        /// shared between manifolds, without any locking. Recursive, because callbacks into
+        /// Rust may use other manifolds.
+        std::unique_lock<std::recursive_mutex> lock_csg()
+        {
+            static std::recursive_mutex mutex;
+            return std::unique_lock<std::recursive_mutex>(mutex);
+        }
+    }
+
     Polygons::Polygons() : polygons(std::make_unique<::manifold::Polygons>()) {}
     Polygons::Polygons(::manifold::Polygons &&polygons) : polygons(std::make_unique<::manifold::Polygons>(std::move(polygons))) {}
     Polygons::~Polygons() {}
@@ -23,80 +42,373 @@ namespace manifold_rs
         return rust::Slice<const double>(static_cast<double *>((void *)polygon.data()), polygon.size() * 2);
     }
 
+    void Polygons::push(rust::Slice<const double> polygon)
+    {
+        ::manifold::SimplePolygon p(polygon.size() / 2);
+        memcpy((void *)p.data(), polygon.data(), p.size() * 2 * sizeof(double));
+        polygons->push_back(std::move(p));
+    }
+
+    std::unique_ptr<Polygons> Polygons::copy() const
+    {
+        return std::make_unique<Polygons>(::manifold::Polygons(*polygons));
+    }
+
+    std::unique_ptr<std::vector<uint32_t>> Polygons::triangulate(double epsilon) const
+    {
+        auto triangles = ::manifold::Triangulate(*polygons, epsilon);
+        auto indices = std::make_unique<std::vector<uint32_t>>();
+        indices->reserve(triangles.size() * 3);
+        for (auto &triangle : triangles)
+        {
+            indices->push_back(triangle.x);
+            indices->push_back(triangle.y);
+            indices->push_back(triangle.z);
+        }
+        return indices;
+    }
+
+    std::unique_ptr<Polygons> polygons_empty()
+    {
+        return std::make_unique<Polygons>();
+    }
+
+    PolygonsVec::PolygonsVec(std::vector<::manifold::Polygons> &&polygons) : polygons(std::move(polygons)) {}
+
+    size_t PolygonsVec::size() const
+    {
+        return polygons.size();
+    }
+
+    std::unique_ptr<Polygons> PolygonsVec::take(size_t index)
+    {
+        return std::make_unique<Polygons>(std::move(polygons.at(index)));
+    }
+
     Manifold::Manifold() : manifold(std::make_unique<::manifold::Manifold>()) {}
     Manifold::Manifold(::manifold::Manifold &&manifold) : manifold(std::make_unique<::manifold::Manifold>(std::move(manifold))) {}
     Manifold::~Manifold() {}
 
     bool Manifold::is_empty() const
     {
+        auto lock = lock_csg();
         return manifold->IsEmpty();
     }
 
+    std::unique_ptr<Manifold> Manifold::copy() const
+    {
+        auto lock = lock_csg();
+        return std::make_unique<Manifold>(::manifold::Manifold(*manifold));
+    }
+
+    std::int32_t Manifold::status() const
+    {
+        auto lock = lock_csg();
+        return static_cast<std::int32_t>(manifold->Status());
+    }
+
+    double Manifold::volume() const
+    {
+        auto lock = lock_csg();
+        return manifold->Volume();
+    }
+
+    double Manifold::surface_area() const
+    {
+        auto lock = lock_csg();
+        return manifold->SurfaceArea();
+    }
+
+    std::int32_t Manifold::genus() const
+    {
+        auto lock = lock_csg();
+        return manifold->Genus();
+    }
+
+    size_t Manifold::num_vert() const
+    {
+        auto lock = lock_csg();
+        return manifold->NumVert();
+    }
+
+    size_t Manifold::num_edge() const
+    {
+        auto lock = lock_csg();
+        return manifold->NumEdge();
+    }
+
+    size_t Manifold::num_tri() const
+    {
+        auto lock = lock_csg();
+        return manifold->NumTri();
+    }
+
+    size_t Manifold::num_prop() const
+    {
+        auto lock = lock_csg();
+        return manifold->NumProp();
+    }
+
+    BoundingBox Manifold::bounding_box() const
+    {
+        auto lock = lock_csg();
+        auto box = manifold->BoundingBox();
+        return BoundingBox{{box.min.x, box.min.y, box.min.z}, {box.max.x, box.max.y, box.max.z}};
+    }
+
+    double Manifold::tolerance() const
+    {
+        auto lock = lock_csg();
+        return manifold->GetTolerance();
+    }
+
+    double Manifold::min_gap(const Manifold &other, double search_length) const
+    {
+        auto lock = lock_csg();
+        return manifold->MinGap(*other.manifold, search_length);
+    }
+
     std::unique_ptr<Polygons> Manifold::slice(double height) const
     {
+        auto lock = lock_csg();
         return std::make_unique<Polygons>(manifold->Slice(height));
     }
 
+    std::unique_ptr<PolygonsVec> Manifold::slice_layers(rust::Slice<const double> heights, bool parallel) const
+    {
+        auto lock = lock_csg();
+        std::vector<::manifold::Polygons> layers(heights.size());
+        // Evaluate pending operations once before slicing
+        manifold->NumTri();
+
+        size_t num_threads = parallel ? std::max(1u, std::thread::hardware_concurrency()) : 1;
+        num_threads = std::min(num_threads, heights.size());
+        auto slice_every = [&](size_t first)
+        {
+            for (size_t i = first; i < heights.size(); i += num_threads)
+            {
+                layers[i] = manifold->Slice(heights[i]);
+            }
+        };
+
+        if (num_threads <= 1)
+        {
+            slice_every(0);
+        }
+        else
+        {
+            std::vector<std::thread> threads;
+            for (size_t t = 0; t < num_threads; ++t)
+            {
+                threads.emplace_back(slice_every, t);
+            }
+            for (auto &thread : threads)
+            {
+                thread.join();
+            }
+        }
+        return std::make_unique<PolygonsVec>(std::move(layers));
+    }
+
     std::unique_ptr<Polygons> Manifold::project() const
     {
+        auto lock = lock_csg();
         return std::make_unique<Polygons>(manifold->Project());
     }
 
     std::unique_ptr<Manifold> Manifold::trim_by_plane(double x, double y, double z, double offset) const
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(manifold->TrimByPlane({x, y, z}, offset));
     }
 
+    std::unique_ptr<ManifoldVec> Manifold::split(const Manifold &cutter) const
+    {
+        auto lock = lock_csg();
+        auto [first, second] = manifold->Split(*cutter.manifold);
+        return std::make_unique<ManifoldVec>(std::vector<::manifold::Manifold>{first, second});
+    }
+
+    std::unique_ptr<ManifoldVec> Manifold::split_by_plane(double x, double y, double z, double offset) const
+    {
+        auto lock = lock_csg();
+        auto [first, second] = manifold->SplitByPlane({x, y, z}, offset);
+        return std::make_unique<ManifoldVec>(std::vector<::manifold::Manifold>{first, second});
+    }
+
+    std::unique_ptr<ManifoldVec> Manifold::decompose() const
+    {
+        auto lock = lock_csg();
+        return std::make_unique<ManifoldVec>(manifold->Decompose());
+    }
+
     std::unique_ptr<Manifold> Manifold::hull() const
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(manifold->Hull());
     }
 
     std::unique_ptr<Manifold> Manifold::translate(double x, double y, double z) const
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(manifold->Translate({x, y, z}));
     }
 
     std::unique_ptr<Manifold> Manifold::scale(double x, double y, double z) const
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(manifold->Scale({x, y, z}));
     }
     std::unique_ptr<Manifold> Manifold::rotate(double x_degrees, double y_degrees, double z_degrees) const
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(manifold->Rotate(x_degrees, y_degrees, z_degrees));
     }
 
+    std::unique_ptr<Manifold> Manifold::transform(rust::Slice<const double> matrix) const
+    {
+        auto lock = lock_csg();
+        const double *m = matrix.data();
+        ::manifold::mat3x4 transform(
+            {m[0], m[1], m[2]},
+            {m[3], m[4], m[5]},
+            {m[6], m[7], m[8]},
+            {m[9], m[10], m[11]});
+        return std::make_unique<Manifold>(manifold->Transform(transform));
+    }
+
+    std::unique_ptr<Manifold> Manifold::mirror(double x, double y, double z) const
+    {
+        auto lock = lock_csg();
+        return std::make_unique<Manifold>(manifold->Mirror({x, y, z}));
+    }
+
+    std::unique_ptr<Manifold> Manifold::warp(WarpContext &ctx) const
+    {
+        auto lock = lock_csg();
+        return std::make_unique<Manifold>(manifold->Warp([&ctx](::manifold::vec3 &v)
+                                                         { warp_vertex(ctx, rust::Slice<double>(&v.x, 3)); }));
+    }
+
+    std::unique_ptr<Manifold> Manifold::warp_batch(WarpBatchContext &ctx) const
+    {
+        auto lock = lock_csg();
+        return std::make_unique<Manifold>(manifold->WarpBatch([&ctx](::manifold::VecView<::manifold::vec3> vertices)
+                                                              { warp_vertices(ctx, vertices.size() > 0 ? rust::Slice<double>(&vertices.begin()->x, vertices.size() * 3) : rust::Slice<double>()); }));
+    }
+
+    std::unique_ptr<Manifold> Manifold::as_original() const
+    {
+        auto lock = lock_csg();
+        return std::make_unique<Manifold>(manifold->AsOriginal());
+    }
+
+    std::int32_t Manifold::original_id() const
+    {
+        auto lock = lock_csg();
+        return manifold->OriginalID();
+    }
+
+    uint32_t reserve_ids(uint32_t n)
+    {
+        return ::manifold::Manifold::ReserveIDs(n);
+    }
+
+    std::unique_ptr<Manifold> Manifold::set_properties(size_t num_prop, PropertiesContext &ctx) const
+    {
+        auto lock = lock_csg();
+        const size_t old_num_prop = manifold->NumProp();
+        return std::make_unique<Manifold>(manifold->SetProperties(num_prop, [&](double *new_prop, ::manifold::vec3 pos, const double *old_prop)
+                                                                  { vertex_properties(ctx,
+                                                                                      num_prop > 0 ? rust::Slice<double>(new_prop, num_prop) : rust::Slice<double>(),
+                                                                                      pos.x, pos.y, pos.z,
+                                                                                      old_num_prop > 0 ? rust::Slice<const double>(old_prop, old_num_prop) : rust::Slice<const double>()); }));
+    }
+
     std::unique_ptr<Manifold> Manifold::refine(int32_t n) const
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(manifold->Refine(n));
     }
 
     std::unique_ptr<Manifold> Manifold::refine_to_length(double t) const
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(manifold->RefineToLength(t));
     }
 
     std::unique_ptr<Manifold> Manifold::refine_to_tolerance(double t) const
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(manifold->RefineToTolerance(t));
     }
 
     std::unique_ptr<Manifold> Manifold::smooth_by_normals(std::int32_t n) const
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(manifold->SmoothByNormals(n));
     }
 
     std::unique_ptr<Manifold> Manifold::smooth_out(double min_sharp_angle, double min_smoothness) const
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(manifold->SmoothOut(min_sharp_angle, min_smoothness));
     }
 
     /// Calculate normals for the manifold and return a new one.
     std::unique_ptr<Manifold> Manifold::calculate_normals(std::int32_t normal_idx, double min_sharp_angle) const
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(manifold->CalculateNormals(normal_idx, min_sharp_angle));
     }
 
+    ManifoldVec::ManifoldVec() {}
+    ManifoldVec::ManifoldVec(std::vector<::manifold::Manifold> &&manifolds) : manifolds(std::move(manifolds)) {}
+
+    void ManifoldVec::push(const Manifold &manifold)
+    {
+        auto lock = lock_csg();
+        manifolds.push_back(*manifold.manifold);
+    }
+
+    size_t ManifoldVec::size() const
+    {
+        return manifolds.size();
+    }
+
+    std::unique_ptr<Manifold> ManifoldVec::get(size_t index) const
+    {
+        return std::make_unique<Manifold>(::manifold::Manifold(manifolds.at(index)));
+    }
+
+    std::unique_ptr<Manifold> compose(const ManifoldVec &manifolds)
+    {
+        auto lock = lock_csg();
+        return std::make_unique<Manifold>(::manifold::Manifold::Compose(manifolds.manifolds));
+    }
+
+    std::unique_ptr<ManifoldVec> manifold_vec()
+    {
+        return std::make_unique<ManifoldVec>();
+    }
+
+    std::unique_ptr<Manifold> batch_hull(const ManifoldVec &manifolds)
+    {
+        auto lock = lock_csg();
+        return std::make_unique<Manifold>(::manifold::Manifold::Hull(manifolds.manifolds));
+    }
+
+    std::unique_ptr<Manifold> hull_points(rust::Slice<const double> points)
+    {
+        std::vector<::manifold::vec3> vertices;
+        vertices.reserve(points.size() / 3);
+        for (size_t i = 0; i + 2 < points.size(); i += 3)
+        {
+            vertices.push_back({points[i], points[i + 1], points[i + 2]});
+        }
+        return std::make_unique<Manifold>(::manifold::Manifold::Hull(vertices));
+    }
+
     std::unique_ptr<Manifold> empty()
     {
         return std::make_unique<Manifold>();
@@ -122,21 +434,212 @@ namespace manifold_rs
         return std::make_unique<Manifold>(::manifold::Manifold::Cylinder(height, radius_low, radius_height, circular_segments));
     }
 
+    std::unique_ptr<Manifold> level_set(const SdfContext &ctx, const BoundingBox &bounds,
+                                        double edge_length, double level, double tolerance)
+    {
+        ::manifold::Box box;
+        box.min = {bounds.min[0], bounds.min[1], bounds.min[2]};
+        box.max = {bounds.max[0], bounds.max[1], bounds.max[2]};
+        return std::make_unique<Manifold>(::manifold::Manifold::LevelSet(
+            [&ctx](::manifold::vec3 p)
+            { return sdf_value(ctx, p.x, p.y, p.z); },
+            box, edge_length, level, tolerance));
+    }
+
     std::unique_ptr<Manifold> union_(const Manifold &a, const Manifold &b)
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(a.manifold->Boolean(*b.manifold, ::manifold::OpType::Add));
     }
 
     std::unique_ptr<Manifold> intersection(const Manifold &a, const Manifold &b)
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(a.manifold->Boolean(*b.manifold, ::manifold::OpType::Intersect));
     }
 
     std::unique_ptr<Manifold> difference(const Manifold &a, const Manifold &b)
     {
+        auto lock = lock_csg();
         return std::make_unique<Manifold>(a.manifold->Boolean(*b.manifold, ::manifold::OpType::Subtract));
     }
 
+    std::unique_ptr<Manifold> batch_union(const ManifoldVec &manifolds)
+    {
+        auto lock = lock_csg();
+        return std::make_unique<Manifold>(::manifold::Manifold::BatchBoolean(manifolds.manifolds, ::manifold::OpType::Add));
+    }
+
+    std::unique_ptr<Manifold> batch_intersection(const ManifoldVec &manifolds)
+    {
+        auto lock = lock_csg();
+        return std::make_unique<Manifold>(::manifold::Manifold::BatchBoolean(manifolds.manifolds, ::manifold::OpType::Intersect));
+    }
+
+    std::unique_ptr<Manifold> batch_difference(const ManifoldVec &manifolds)
+    {
+        auto lock = lock_csg();
+        return std::make_unique<Manifold>(::manifold::Manifold::BatchBoolean(manifolds.manifolds, ::manifold::OpType::Subtract));
+    }
+
+    namespace
+    {
+        /// Vertex positions of a manifold
+        std::vector<::manifold::vec3> positions(const ::manifold::Manifold &m)
+        {
+            auto mesh = m.GetMeshGL64();
+            std::vector<::manifold::vec3> result(mesh.NumVert());
+            for (size_t i = 0; i < result.size(); ++i)
+            {
+                const double *p = &mesh.vertProperties[i * mesh.numProp];
+                result[i] = {p[0], p[1], p[2]};
+            }
+            return result;
+        }
+
+        /// Is the manifold convex up to a small relative volume error?
+        bool is_convex(const ::manifold::Manifold &m)
+        {
+            double volume = m.Volume();
+            return m.Hull().Volume() - volume <= 1e-6 * volume;
+        }
+
+        /// Find the plane of a face which has vertices on both sides
+        bool concave_face_plane(const ::manifold::Manifold &m, ::manifold::vec3 &normal, double &offset)
+        {
+            auto mesh = m.GetMeshGL64();
+            auto vertices = positions(m);
+            const double epsilon = std::max(m.GetTolerance(), 1e-9);
+            for (size_t i = 0; i < mesh.NumTri(); ++i)
+            {
+                auto &a = vertices[mesh.triVerts[i * 3]];
+                auto &b = vertices[mesh.triVerts[i * 3 + 1]];
+                auto &c = vertices[mesh.triVerts[i * 3 + 2]];
+                normal = ::manifold::la::cross(b - a, c - a);
+                double length = ::manifold::la::length(normal);
+                if (length == 0.0)
+                    continue;
+                normal /= length;
+                offset = ::manifold::la::dot(normal, a);
+
+                bool above = false, below = false;
+                for (auto &v : vertices)
+                {
+                    double distance = ::manifold::la::dot(normal, v) - offset;
+                    above |= distance > epsilon;
+                    below |= distance < -epsilon;
+                }
+                if (above && below)
+                    return true;
+            }
+            return false;
+        }
+
+        /// Split a manifold into convex parts along the planes of its concave faces
+        void convex_parts(const ::manifold::Manifold &m, std::vector<::manifold::Manifold> &parts, int depth = 0)
+        {
+            for (auto &component : m.Decompose())
+            {
+                ::manifold::vec3 normal;
+                double offset;
+                // The depth limit only guards against tolerance issues
+                if (depth >= 32 || is_convex(component) || !concave_face_plane(component, normal, offset))
+                {
+                    parts.push_back(component);
+                    continue;
+                }
+                auto halves = component.SplitByPlane(normal, offset);
+                convex_parts(halves.first, parts, depth + 1);
+                convex_parts(halves.second, parts, depth + 1);
+            }
+        }
+
+        ::manifold::Manifold minkowski_sum_fallback(const ::manifold::Manifold &a, const ::manifold::Manifold &b)
+        {
+            if (a.IsEmpty() || b.IsEmpty())
+                return {};
+
+            std::vector<::manifold::Manifold> parts_a, parts_b;
+            convex_parts(a, parts_a);
+            convex_parts(b, parts_b);
+
+            // The sum of two convex parts is the hull of one part translated to each vertex of the other
+            std::vector<::manifold::Manifold> sums;
+            for (auto &part_a : parts_a)
+            {
+                auto vertices = positions(part_a);
+                for (auto &part_b : parts_b)
+                {
+                    std::vector<::manifold::Manifold> translated;
+                    translated.reserve(vertices.size());
+                    for (auto &v : vertices)
+                        translated.push_back(part_b.Translate(v));
+                    sums.push_back(::manifold::Manifold::Hull(translated));
+                }
+            }
+            return ::manifold::Manifold::BatchBoolean(sums, ::manifold::OpType::Add);
+        }
+
+        ::manifold::Manifold minkowski_difference_fallback(const ::manifold::Manifold &a, const ::manifold::Manifold &b)
+        {
+            if (a.IsEmpty() || b.IsEmpty())
+                return {};
+
+            // For p within this box, b + p lies within the bounding box of a
+            auto box_a = a.BoundingBox();
+            auto box_b = b.BoundingBox();
+            auto min = box_a.min - box_b.min;
+            auto size = box_a.max - box_b.max - min;
+            if (size.x <= 0.0 || size.y <= 0.0 || size.z <= 0.0)
+                return {};
+            auto candidates = ::manifold::Manifold::Cube(size).Translate(min);
+
+            // b + p lies within a if it does not touch the complement of a within a slightly larger box
+            auto margin = 0.01 * (box_a.max - box_a.min);
+            auto outer = ::manifold::Manifold::Cube(box_a.max - box_a.min + 2.0 * margin).Translate(box_a.min - margin);
+            auto complement = outer.Boolean(a, ::manifold::OpType::Subtract);
+            auto reflected = b.Scale({-1.0, -1.0, -1.0});
+            return candidates.Boolean(minkowski_sum_fallback(complement, reflected), ::manifold::OpType::Subtract);
+        }
+
+        // Prefer the upstream implementation if the manifold library provides one
+        template <typename M>
+        auto minkowski_sum_impl(const M &a, const M &b, int) -> decltype(a.MinkowskiSum(b))
+        {
+            return a.MinkowskiSum(b);
+        }
+
+        template <typename M>
+        M minkowski_sum_impl(const M &a, const M &b, long)
+        {
+            return minkowski_sum_fallback(a, b);
+        }
+
+        template <typename M>
+        auto minkowski_difference_impl(const M &a, const M &b, int) -> decltype(a.MinkowskiDifference(b))
+        {
+            return a.MinkowskiDifference(b);
+        }
+
+        template <typename M>
+        M minkowski_difference_impl(const M &a, const M &b, long)
+        {
+            return minkowski_difference_fallback(a, b);
+        }
+    } // namespace
+
+    std::unique_ptr<Manifold> minkowski_sum(const Manifold &a, const Manifold &b)
+    {
+        auto lock = lock_csg();
+        return std::make_unique<Manifold>(minkowski_sum_impl(*a.manifold, *b.manifold, 0));
+    }
+
+    std::unique_ptr<Manifold> minkowski_difference(const Manifold &a, const Manifold &b)
+    {
+        auto lock = lock_csg();
+        return std::make_unique<Manifold>(minkowski_difference_impl(*a.manifold, *b.manifold, 0));
+    }
+
     Mesh::Mesh() : mesh(std::make_unique<::manifold::Mesh>()) {}
 
     Mesh::Mesh(::manifold::Mesh &&mesh) : mesh(std::make_unique<::manifold::Mesh>(std::move(mesh))) {}
@@ -148,19 +651,76 @@ namespace manifold_rs
         return mesh->numProp;
     }
 
-    std::unique_ptr<std::vector<float>> Mesh::vertices() const
+    std::unique_ptr<Mesh> Mesh::copy() const
+    {
+        return std::make_unique<Mesh>(::manifold::Mesh(*mesh));
+    }
+
+    size_t Mesh::num_vert() const
+    {
+        return mesh->NumVert();
+    }
+
+    size_t Mesh::num_tri() const
+    {
+        return mesh->NumTri();
+    }
+
+    rust::Slice<const uint32_t> Mesh::run_index() const
+    {
+        return {mesh->runIndex.data(), mesh->runIndex.size()};
+    }
+
+    rust::Slice<const uint32_t> Mesh::run_original_id() const
+    {
+        return {mesh->runOriginalID.data(), mesh->runOriginalID.size()};
+    }
+
+    rust::Slice<const float> Mesh::run_transform() const
+    {
+        return {mesh->runTransform.data(), mesh->runTransform.size()};
+    }
+
+    rust::Slice<const uint32_t> Mesh::face_id() const
     {
-        return std::make_unique<std::vector<float>>(mesh->vertProperties);
+        return {mesh->faceID.data(), mesh->faceID.size()};
     }
 
-    std::unique_ptr<std::vector<uint32_t>> Mesh::indices() const
+    rust::Slice<const uint32_t> Mesh::merge_from_vert() const
     {
-        return std::make_unique<std::vector<uint32_t>>(mesh->triVerts);
+        return {mesh->mergeFromVert.data(), mesh->mergeFromVert.size()};
     }
 
-    std::unique_ptr<Mesh> mesh_from_manifold(const Manifold &manifold)
+    rust::Slice<const uint32_t> Mesh::merge_to_vert() const
     {
-        auto mesh = manifold.manifold->GetMeshGL(0);
+        return {mesh->mergeToVert.data(), mesh->mergeToVert.size()};
+    }
+
+    void Mesh::set_merge(rust::Slice<const uint32_t> merge_from_vert, rust::Slice<const uint32_t> merge_to_vert)
+    {
+        mesh->mergeFromVert.assign(merge_from_vert.begin(), merge_from_vert.end());
+        mesh->mergeToVert.assign(merge_to_vert.begin(), merge_to_vert.end());
+    }
+
+    bool Mesh::merge()
+    {
+        return mesh->Merge();
+    }
+
+    rust::Slice<const float> Mesh::vertex_slice() const
+    {
+        return {mesh->vertProperties.data(), mesh->vertProperties.size()};
+    }
+
+    rust::Slice<const uint32_t> Mesh::index_slice() const
+    {
+        return {mesh->triVerts.data(), mesh->triVerts.size()};
+    }
+
+    std::unique_ptr<Mesh> mesh_from_manifold(const Manifold &manifold, int32_t normal_idx)
+    {
+        auto lock = lock_csg();
+        auto mesh = manifold.manifold->GetMeshGL(normal_idx);
         return std::make_unique<Mesh>(std::move(mesh));
     }
 
@@ -170,26 +730,80 @@ namespace manifold_rs
     }
 
     std::unique_ptr<Mesh> mesh_from_vertices(
+        uint32_t num_prop,
         rust::Slice<const float> vertices,
         rust::Slice<const uint32_t> indices)
     {
-        assert(vertices.size() % 3 == 0);
-        assert(indices.size() % 3 == 0);
         ::manifold::Mesh mesh;
-        mesh.numProp = 3;
+        mesh.numProp = num_prop;
         mesh.vertProperties = std::vector<float>(vertices.begin(), vertices.end());
         mesh.triVerts = std::vector<uint32_t>(indices.begin(), indices.end());
 
         return std::make_unique<Mesh>(std::move(mesh));
     }
 
+    Mesh64::Mesh64(::manifold::MeshGL64 &&mesh) : mesh(std::make_unique<::manifold::MeshGL64>(std::move(mesh))) {}
+
+    std::uint32_t Mesh64::num_props() const
+    {
+        return mesh->numProp;
+    }
+
+    std::unique_ptr<Mesh64> Mesh64::copy() const
+    {
+        return std::make_unique<Mesh64>(::manifold::MeshGL64(*mesh));
+    }
+
+    size_t Mesh64::num_vert() const
+    {
+        return mesh->NumVert();
+    }
+
+    size_t Mesh64::num_tri() const
+    {
+        return mesh->NumTri();
+    }
+
+    rust::Slice<const double> Mesh64::vertex_slice() const
+    {
+        return {mesh->vertProperties.data(), mesh->vertProperties.size()};
+    }
+
+    rust::Slice<const uint64_t> Mesh64::index_slice() const
+    {
+        return {mesh->triVerts.data(), mesh->triVerts.size()};
+    }
+
+    std::unique_ptr<Mesh64> mesh64_from_manifold(const Manifold &manifold, int32_t normal_idx)
+    {
+        auto lock = lock_csg();
+        auto mesh = manifold.manifold->GetMeshGL64(normal_idx);
+        return std::make_unique<Mesh64>(std::move(mesh));
+    }
+
+    std::unique_ptr<Manifold> manifold_from_mesh64(const Mesh64 &mesh)
+    {
+        return std::make_unique<Manifold>(::manifold::Manifold(*mesh.mesh));
+    }
+
+    std::unique_ptr<Mesh64> mesh64_from_vertices(
+        uint32_t num_prop,
+        rust::Slice<const double> vertices,
+        rust::Slice<const uint64_t> indices)
+    {
+        ::manifold::MeshGL64 mesh;
+        mesh.numProp = num_prop;
+        mesh.vertProperties = std::vector<double>(vertices.begin(), vertices.end());
+        mesh.triVerts = std::vector<uint64_t>(indices.begin(), indices.end());
+
+        return std::make_unique<Mesh64>(std::move(mesh));
+    }
+
     ::manifold::Polygons to_polygons(rust::Slice<const rust::Slice<const double>> multi_polygon_data)
     {
         ::manifold::Polygons polygons;
         for (auto &polygon_data : multi_polygon_data)
         {
-            assert(polygon_data.size() % 2 == 0);
-
             // Create SimplePolygon from rust::Slice<const float> via memcpy
             // without using a loop
             ::manifold::SimplePolygon p(polygon_data.size() / 2);
@@ -199,6 +813,23 @@ namespace manifold_rs
         return polygons;
     }
 
+    std::unique_ptr<Manifold> extrude_polygons(
+        const Polygons &polygons,
+        double height, uint32_t divisions, double twist_degrees, double scale_top_x, double scale_top_y)
+    {
+        return std::make_unique<Manifold>(::manifold::Manifold::Extrude(*polygons.polygons, height, divisions, twist_degrees, {scale_top_x, scale_top_y}));
+    }
+
+    std::unique_ptr<Manifold> revolve_polygons(const Polygons &polygons, uint32_t circular_segments, double revolve_degrees)
+    {
+        return std::make_unique<Manifold>(::manifold::Manifold::Revolve(*polygons.polygons, circular_segments, revolve_degrees));
+    }
+
+    std::unique_ptr<Polygons> polygons_from_data(rust::Slice<const rust::Slice<const double>> multi_polygon_data)
+    {
+        return std::make_unique<Polygons>(to_polygons(multi_polygon_data));
+    }
+
     std::unique_ptr<Manifold> extrude(
         rust::Slice<const rust::Slice<const double>> multi_polygon_data,
         double height, uint32_t divisions, double twist_degrees, double scale_top_x, double scale_top_y)
@@ -213,4 +844,129 @@ namespace manifold_rs
         return std::make_unique<Manifold>(::manifold::Manifold::Revolve(to_polygons(multi_polygon_data), circular_segments, revolve_degrees));
     }
 
+    CrossSection::CrossSection() : cross_section(std::make_unique<::manifold::CrossSection>()) {}
+    CrossSection::CrossSection(::manifold::CrossSection &&cross_section) : cross_section(std::make_unique<::manifold::CrossSection>(std::move(cross_section))) {}
+    CrossSection::~CrossSection() {}
+
+    bool CrossSection::is_empty() const
+    {
+        return cross_section->IsEmpty();
+    }
+
+    double CrossSection::area() const
+    {
+        return cross_section->Area();
+    }
+
+    size_t CrossSection::num_vert() const
+    {
+        return cross_section->NumVert();
+    }
+
+    size_t CrossSection::num_contour() const
+    {
+        return cross_section->NumContour();
+    }
+
+    std::unique_ptr<CrossSection> CrossSection::translate(double x, double y) const
+    {
+        return std::make_unique<CrossSection>(cross_section->Translate({x, y}));
+    }
+
+    std::unique_ptr<CrossSection> CrossSection::rotate(double degrees) const
+    {
+        return std::make_unique<CrossSection>(cross_section->Rotate(degrees));
+    }
+
+    std::unique_ptr<CrossSection> CrossSection::scale(double x, double y) const
+    {
+        return std::make_unique<CrossSection>(cross_section->Scale({x, y}));
+    }
+
+    std::unique_ptr<CrossSection> CrossSection::mirror(double x, double y) const
+    {
+        return std::make_unique<CrossSection>(cross_section->Mirror({x, y}));
+    }
+
+    std::unique_ptr<CrossSection> CrossSection::offset(double delta, std::uint8_t join_type, double miter_limit, std::int32_t circular_segments) const
+    {
+        using JoinType = ::manifold::CrossSection::JoinType;
+        JoinType join;
+        switch (join_type)
+        {
+        case 0:
+            join = JoinType::Square;
+            break;
+        case 1:
+            join = JoinType::Round;
+            break;
+        default:
+            join = JoinType::Miter;
+            break;
+        }
+        return std::make_unique<CrossSection>(cross_section->Offset(delta, join, miter_limit, circular_segments));
+    }
+
+    std::unique_ptr<CrossSection> CrossSection::hull() const
+    {
+        return std::make_unique<CrossSection>(cross_section->Hull());
+    }
+
+    std::unique_ptr<Polygons> CrossSection::to_polygons() const
+    {
+        return std::make_unique<Polygons>(cross_section->ToPolygons());
+    }
+
+    std::unique_ptr<CrossSection> cross_section_empty()
+    {
+        return std::make_unique<CrossSection>();
+    }
+
+    std::unique_ptr<CrossSection> cross_section_square(double x_size, double y_size, bool center)
+    {
+        return std::make_unique<CrossSection>(::manifold::CrossSection::Square({x_size, y_size}, center));
+    }
+
+    std::unique_ptr<CrossSection> cross_section_circle(double radius, std::int32_t circular_segments)
+    {
+        return std::make_unique<CrossSection>(::manifold::CrossSection::Circle(radius, circular_segments));
+    }
+
+    std::unique_ptr<CrossSection> cross_section_from_polygons(const Polygons &polygons, std::uint8_t fill_rule)
+    {
+        using FillRule = ::manifold::CrossSection::FillRule;
+        FillRule rule;
+        switch (fill_rule)
+        {
+        case 0:
+            rule = FillRule::EvenOdd;
+            break;
+        case 1:
+            rule = FillRule::NonZero;
+            break;
+        case 2:
+            rule = FillRule::Positive;
+            break;
+        default:
+            rule = FillRule::Negative;
+            break;
+        }
+        return std::make_unique<CrossSection>(::manifold::CrossSection(*polygons.polygons, rule));
+    }
+
+    std::unique_ptr<CrossSection> cross_section_union(const CrossSection &a, const CrossSection &b)
+    {
+        return std::make_unique<CrossSection>(a.cross_section->Boolean(*b.cross_section, ::manifold::OpType::Add));
+    }
+
+    std::unique_ptr<CrossSection> cross_section_intersection(const CrossSection &a, const CrossSection &b)
+    {
+        return std::make_unique<CrossSection>(a.cross_section->Boolean(*b.cross_section, ::manifold::OpType::Intersect));
+    }
+
+    std::unique_ptr<CrossSection> cross_section_difference(const CrossSection &a, const CrossSection &b)
+    {
+        return std::make_unique<CrossSection>(a.cross_section->Boolean(*b.cross_section, ::manifold::OpType::Subtract));
+    }
+
 } // namespace manifold_rs
@@ -7,6 +7,7 @@
 
 #include <memory>
 #include <manifold/manifold.h>
+#include <manifold/cross_section.h>
 
 namespace manifold
 {
@@ -15,6 +16,13 @@ namespace manifold
 
 namespace manifold_rs
 {
+    // Shared and Rust types, defined in the generated bridge header
+    struct BoundingBox;
+    struct WarpContext;
+    struct WarpBatchContext;
+    struct PropertiesContext;
+    struct SdfContext;
+
     /// @brief Wrapper around manifold::Polygons
     /// @details This class will be exposed to Rust
     class Polygons
@@ -30,9 +38,42 @@ namespace manifold_rs
         /// @brief Get a polygon by index as a slice of doubles
         rust::Slice<const double> get_as_slice(size_t index) const;
 
+        /// @brief Append a polygon
+        /// @param polygon A slice of doubles where each 2 elements represent a point (x, y)
+        void push(rust::Slice<const double> polygon);
+
+        /// @brief Copy the polygons
+        std::unique_ptr<Polygons> copy() const;
+
+        /// @brief Triangulate the polygons
+        /// @param epsilon Precision, negative for a default
+        /// @return Three indices per triangle, referring to the points of all polygons in order
+        std::unique_ptr<std::vector<uint32_t>> triangulate(double epsilon) const;
+
         std::unique_ptr<::manifold::Polygons> polygons;
     };
 
+    /// @brief Create empty polygons
+    std::unique_ptr<Polygons> polygons_empty();
+
+    /// @brief Vector of polygons
+    /// @details This class will be exposed to Rust
+    class PolygonsVec
+    {
+    public:
+        PolygonsVec(std::vector<::manifold::Polygons> &&polygons);
+
+        /// @brief Get the number of entries
+        size_t size() const;
+
+        /// @brief Move the polygons out of an entry
+        std::unique_ptr<Polygons> take(size_t index);
+
+        std::vector<::manifold::Polygons> polygons;
+    };
+
+    class ManifoldVec;
+
     /// @brief Wrapper around manifold::Manifold
     /// @details This class will be exposed to Rust
     class Manifold
@@ -45,9 +86,54 @@ namespace manifold_rs
         /// @brief Does Manifold have triangles?
         bool is_empty() const;
 
+        /// @brief Copy the manifold, sharing the CSG tree
+        std::unique_ptr<Manifold> copy() const;
+
+        /// @brief Get the error status of the manifold
+        /// @return The C++ `manifold::Manifold::Error` code, 0 if there is no error
+        std::int32_t status() const;
+
+        /// @brief Get the volume of the manifold
+        double volume() const;
+
+        /// @brief Get the surface area of the manifold
+        double surface_area() const;
+
+        /// @brief Get the genus of the manifold
+        std::int32_t genus() const;
+
+        /// @brief Get the number of vertices
+        size_t num_vert() const;
+
+        /// @brief Get the number of edges
+        size_t num_edge() const;
+
+        /// @brief Get the number of triangles
+        size_t num_tri() const;
+
+        /// @brief Get the number of vertex properties
+        size_t num_prop() const;
+
+        /// @brief Get the axis-aligned bounding box
+        BoundingBox bounding_box() const;
+
+        /// @brief Get the tolerance of the manifold
+        double tolerance() const;
+
+        /// @brief Get the minimum gap between two manifolds
+        /// @param other The other manifold
+        /// @param search_length Maximum distance to search for
+        /// @return The gap, at most `search_length`
+        double min_gap(const Manifold &other, double search_length) const;
+
         /// @brief Slice the manifold at a given height
         std::unique_ptr<Polygons> slice(double height) const;
 
+        /// @brief Slice the manifold at several heights
+        /// @param heights Heights of the slices
+        /// @param parallel Slice on all hardware threads
+        std::unique_ptr<PolygonsVec> slice_layers(rust::Slice<const double> heights, bool parallel) const;
+
         /// @brief Project the manifold
         std::unique_ptr<Polygons> project() const;
 
@@ -58,6 +144,22 @@ namespace manifold_rs
         /// @param offset Offset of the plane
         std::unique_ptr<Manifold> trim_by_plane(double x, double y, double z, double offset) const;
 
+        /// @brief Split the manifold by another manifold
+        /// @param cutter The manifold to split by
+        /// @return The intersection and the difference with the cutter
+        std::unique_ptr<ManifoldVec> split(const Manifold &cutter) const;
+
+        /// @brief Split the manifold by a plane
+        /// @param x X coordinate of normal vector of the plane
+        /// @param y Y coordinate of normal vector of the plane
+        /// @param z Z coordinate of normal vector of the plane
+        /// @param offset Offset of the plane
+        /// @return The parts in direction of the normal and opposite to it
+        std::unique_ptr<ManifoldVec> split_by_plane(double x, double y, double z, double offset) const;
+
+        /// @brief Decompose the manifold into connected components
+        std::unique_ptr<ManifoldVec> decompose() const;
+
         /// @brief Calculate the convex hull
         std::unique_ptr<Manifold> hull() const;
 
@@ -71,6 +173,32 @@ namespace manifold_rs
         std::unique_ptr<Manifold> rotate(double x_degrees, double y_degrees = 0.0,
                                          double z_degrees = 0.0) const;
 
+        /// @brief Apply an affine transformation
+        /// @param matrix 4x3 matrix as 12 doubles in column-major order, the last column is the translation
+        std::unique_ptr<Manifold> transform(rust::Slice<const double> matrix) const;
+
+        /// @brief Mirror the manifold
+        /// @param x X coordinate of normal vector of the mirror plane
+        /// @param y Y coordinate of normal vector of the mirror plane
+        /// @param z Z coordinate of normal vector of the mirror plane
+        std::unique_ptr<Manifold> mirror(double x, double y, double z) const;
+
+        /// @brief Move each vertex by calling a Rust closure
+        std::unique_ptr<Manifold> warp(WarpContext &ctx) const;
+
+        /// @brief Move all vertices by calling a Rust closure once
+        std::unique_ptr<Manifold> warp_batch(WarpBatchContext &ctx) const;
+
+        /// @brief Mark the manifold as original with a new original ID
+        std::unique_ptr<Manifold> as_original() const;
+
+        /// @brief Get the original ID, -1 if the manifold is not an original
+        std::int32_t original_id() const;
+
+        /// @brief Replace the vertex properties by calling a Rust closure for each vertex
+        /// @param num_prop Number of new properties, not counting the position
+        std::unique_ptr<Manifold> set_properties(size_t num_prop, PropertiesContext &ctx) const;
+
         /// @brief Refine manifold `n` times
         std::unique_ptr<Manifold> refine(std::int32_t n) const;
 
@@ -92,6 +220,39 @@ namespace manifold_rs
         std::unique_ptr<::manifold::Manifold> manifold;
     };
 
+    /// @brief Vector of manifolds
+    /// @details This class will be exposed to Rust
+    class ManifoldVec
+    {
+    public:
+        ManifoldVec();
+        ManifoldVec(std::vector<::manifold::Manifold> &&manifolds);
+
+        /// @brief Append a manifold
+        void push(const Manifold &manifold);
+
+        /// @brief Get the number of manifolds
+        size_t size() const;
+
+        /// @brief Get a copy of a manifold by index
+        std::unique_ptr<Manifold> get(size_t index) const;
+
+        std::vector<::manifold::Manifold> manifolds;
+    };
+
+    /// @brief Create an empty vector of manifolds
+    std::unique_ptr<ManifoldVec> manifold_vec();
+
+    /// @brief Combine disjoint manifolds without a boolean operation
+    std::unique_ptr<Manifold> compose(const ManifoldVec &manifolds);
+
+    /// @brief Calculate the convex hull of several manifolds
+    std::unique_ptr<Manifold> batch_hull(const ManifoldVec &manifolds);
+
+    /// @brief Calculate the convex hull of a set of points
+    /// @param points Points, a slice of doubles where each 3 elements represent a point (x, y, z)
+    std::unique_ptr<Manifold> hull_points(rust::Slice<const double> points);
+
     /// @brief Create a new empty manifold
     std::unique_ptr<Manifold> empty();
 
@@ -119,6 +280,16 @@ namespace manifold_rs
     /// @return A new cylinder as a Manifold
     std::unique_ptr<Manifold> cylinder(double radius_low, double radius_high, double height, uint32_t circular_segments);
 
+    /// @brief Create a manifold from a signed distance function
+    /// @param ctx Rust closure evaluating the signed distance, positive inside
+    /// @param bounds Region in which the function is sampled
+    /// @param edge_length Grid spacing
+    /// @param level Function value of the surface
+    /// @param tolerance Surface tolerance, negative for default
+    /// @return A new manifold
+    std::unique_ptr<Manifold> level_set(const SdfContext &ctx, const BoundingBox &bounds,
+                                        double edge_length, double level, double tolerance);
+
     /// @brief Perform a union operation
     /// @param a First manifold
     /// @param b Second manifold
@@ -137,12 +308,47 @@ namespace manifold_rs
     /// @return A new manifold as a result of the difference operation
     std::unique_ptr<Manifold> difference(const Manifold &a, const Manifold &b);
 
+    /// @brief Calculate the Minkowski sum
+    /// @param a First manifold
+    /// @param b Second manifold
+    /// @return A new manifold containing all points a + b
+    std::unique_ptr<Manifold> minkowski_sum(const Manifold &a, const Manifold &b);
+
+    /// @brief Calculate the Minkowski difference
+    /// @param a First manifold
+    /// @param b Second manifold, must be connected
+    /// @return A new manifold containing all points p for which b + p lies within a
+    std::unique_ptr<Manifold> minkowski_difference(const Manifold &a, const Manifold &b);
+
+    /// @brief Perform a union of many manifolds at once
+    std::unique_ptr<Manifold> batch_union(const ManifoldVec &manifolds);
+
+    /// @brief Perform an intersection of many manifolds at once
+    std::unique_ptr<Manifold> batch_intersection(const ManifoldVec &manifolds);
+
+    /// @brief Subtract all other manifolds from the first one
+    std::unique_ptr<Manifold> batch_difference(const ManifoldVec &manifolds);
+
     /// @brief Extrude a multi-polygon to create a 3D shape
+    /// @note Each polygon must contain an even number of coordinates, validated on the Rust side
     std::unique_ptr<Manifold> extrude(
         rust::Slice<const rust::Slice<const double>> multi_polygon_data,
         double height, uint32_t divisions, double twist_degrees, double scale_top_x, double scale_top_y);
 
+    /// @brief Extrude polygons to create a 3D shape
+    std::unique_ptr<Manifold> extrude_polygons(
+        const Polygons &polygons,
+        double height, uint32_t divisions, double twist_degrees, double scale_top_x, double scale_top_y);
+
+    /// @brief Revolve polygons to create a 3D shape
+    std::unique_ptr<Manifold> revolve_polygons(const Polygons &polygons, uint32_t circular_segments, double angle);
+
+    /// @brief Create polygons from slices of x, y coordinates
+    /// @note Each polygon must contain an even number of coordinates, validated on the Rust side
+    std::unique_ptr<Polygons> polygons_from_data(rust::Slice<const rust::Slice<const double>> multi_polygon_data);
+
     /// @brief Revolve a multi-polygon to create a 3D shape
+    /// @note Each polygon must contain an even number of coordinates, validated on the Rust side
     std::unique_ptr<Manifold> revolve(
         rust::Slice<const rust::Slice<const double>> multi_polygon_data,
         uint32_t circular_segments, double angle);
@@ -159,22 +365,90 @@ namespace manifold_rs
         /// @brief  Number of vertex properties of the mesh.
         std::uint32_t num_props() const;
 
-        /// @brief Get the vertices of the mesh
+        /// @brief Copy the mesh
+        std::unique_ptr<Mesh> copy() const;
+
+        /// @brief Number of vertices of the mesh.
+        size_t num_vert() const;
+
+        /// @brief Number of triangles of the mesh.
+        size_t num_tri() const;
+
+        /// @brief Start index into the indices of each run
+        rust::Slice<const uint32_t> run_index() const;
+
+        /// @brief Original ID of each run
+        rust::Slice<const uint32_t> run_original_id() const;
+
+        /// @brief Column-major 4x3 transform of each run
+        rust::Slice<const float> run_transform() const;
+
+        /// @brief Source face ID of each triangle
+        rust::Slice<const uint32_t> face_id() const;
+
+        /// @brief Vertices merged into other vertices
+        rust::Slice<const uint32_t> merge_from_vert() const;
+
+        /// @brief Vertices the merged vertices are merged into
+        rust::Slice<const uint32_t> merge_to_vert() const;
+
+        /// @brief Replace the merge vectors
+        void set_merge(rust::Slice<const uint32_t> merge_from_vert, rust::Slice<const uint32_t> merge_to_vert);
+
+        /// @brief Merge vertices along open edges
+        /// @return Whether the merge vectors changed
+        bool merge();
+
+        /// @brief View the vertex properties of the mesh
         /// @details The vertex coefficients are in the following order:
         ///          * position x, y, z
-        /// @return A vector of vertices
-        std::unique_ptr<std::vector<float>> vertices() const;
+        ///          * additional properties, up to `num_props()` per vertex
+        rust::Slice<const float> vertex_slice() const;
 
-        /// @brief  Get the indices of the mesh
-        std::unique_ptr<std::vector<uint32_t>> indices() const;
+        /// @brief View the indices of the mesh
+        rust::Slice<const uint32_t> index_slice() const;
 
         std::unique_ptr<::manifold::Mesh> mesh;
     };
 
+    /// @brief A double precision mesh with 64 bit indices
+    /// @details This class will be exposed to Rust
+    class Mesh64
+    {
+    public:
+        Mesh64(::manifold::MeshGL64 &&mesh);
+
+        /// @brief Number of vertex properties of the mesh.
+        std::uint32_t num_props() const;
+
+        /// @brief Copy the mesh
+        std::unique_ptr<Mesh64> copy() const;
+
+        /// @brief Number of vertices of the mesh.
+        size_t num_vert() const;
+
+        /// @brief Number of triangles of the mesh.
+        size_t num_tri() const;
+
+        /// @brief View the vertex properties of the mesh, starting with position x, y, z
+        rust::Slice<const double> vertex_slice() const;
+
+        /// @brief View the indices of the mesh
+        rust::Slice<const uint64_t> index_slice() const;
+
+        std::unique_ptr<::manifold::MeshGL64> mesh;
+    };
+
+    /// @brief Reserve original IDs
+    /// @param n Number of IDs to reserve
+    /// @return The first reserved ID
+    uint32_t reserve_ids(uint32_t n);
+
     /// @brief Create a mesh from a manifold
     /// @param manifold A manifold
+    /// @param normal_idx First of the properties holding normals to update, or -1
     /// @return A new mesh
-    std::unique_ptr<Mesh> mesh_from_manifold(const Manifold &manifold);
+    std::unique_ptr<Mesh> mesh_from_manifold(const Manifold &manifold, int32_t normal_idx);
 
     /// @brief Create a manifold from a mesh
     /// @param mesh A mesh
@@ -182,10 +456,105 @@ namespace manifold_rs
     std::unique_ptr<Manifold> manifold_from_mesh(const Mesh &mesh);
 
     /// @brief Create a mesh from vertices and indices
-    /// @param vertices Vertices, a slice of floats where each 3 elements represent a vertex position (x, y, z)
-    /// @param indices Indices
+    /// @param num_prop Number of properties per vertex, at least 3
+    /// @param vertices Vertices, a slice of floats where each `num_prop` elements represent a vertex, starting with its position (x, y, z)
+    /// @param indices Indices, a slice of vertex indices where each 3 elements represent a triangle
     /// @return A new mesh
+    /// @note The input is validated on the Rust side and must not be malformed
     std::unique_ptr<Mesh> mesh_from_vertices(
+        uint32_t num_prop,
         rust::Slice<const float> vertices,
         rust::Slice<const uint32_t> indices);
+
+    /// @brief Create a double precision mesh from a manifold
+    /// @param normal_idx First of the properties holding normals to update, or -1
+    std::unique_ptr<Mesh64> mesh64_from_manifold(const Manifold &manifold, int32_t normal_idx);
+
+    /// @brief Create a manifold from a double precision mesh
+    std::unique_ptr<Manifold> manifold_from_mesh64(const Mesh64 &mesh);
+
+    /// @brief Create a double precision mesh from vertices and indices
+    /// @note The input is validated on the Rust side and must not be malformed
+    std::unique_ptr<Mesh64> mesh64_from_vertices(
+        uint32_t num_prop,
+        rust::Slice<const double> vertices,
+        rust::Slice<const uint64_t> indices);
+
+    /// @brief Wrapper around manifold::CrossSection
+    /// @details This class will be exposed to Rust
+    class CrossSection
+    {
+    public:
+        CrossSection();
+        CrossSection(::manifold::CrossSection &&cross_section);
+        ~CrossSection();
+
+        /// @brief Does the cross section contain no contours?
+        bool is_empty() const;
+
+        /// @brief Get the area of the cross section
+        double area() const;
+
+        /// @brief Get the number of vertices
+        size_t num_vert() const;
+
+        /// @brief Get the number of contours
+        size_t num_contour() const;
+
+        /// @brief Translate the cross section
+        std::unique_ptr<CrossSection> translate(double x, double y) const;
+
+        /// @brief Rotate the cross section
+        /// @param degrees Angle in degrees, counter-clockwise
+        std::unique_ptr<CrossSection> rotate(double degrees) const;
+
+        /// @brief Scale the cross section
+        std::unique_ptr<CrossSection> scale(double x, double y) const;
+
+        /// @brief Mirror the cross section over the line through the origin with the given normal
+        std::unique_ptr<CrossSection> mirror(double x, double y) const;
+
+        /// @brief Offset the contours
+        /// @param delta Offset distance, negative to shrink
+        /// @param join_type 0: square, 1: round, 2: miter
+        /// @param miter_limit Maximum miter distance as multiple of delta
+        /// @param circular_segments Number of segments of a full circle for round joins
+        std::unique_ptr<CrossSection> offset(double delta, std::uint8_t join_type, double miter_limit, std::int32_t circular_segments) const;
+
+        /// @brief Calculate the convex hull
+        std::unique_ptr<CrossSection> hull() const;
+
+        /// @brief Get the contours as polygons
+        std::unique_ptr<Polygons> to_polygons() const;
+
+        std::unique_ptr<::manifold::CrossSection> cross_section;
+    };
+
+    /// @brief Create an empty cross section
+    std::unique_ptr<CrossSection> cross_section_empty();
+
+    /// @brief Create a rectangle
+    /// @param x_size Size in x direction
+    /// @param y_size Size in y direction
+    /// @param center Center the rectangle at the origin
+    std::unique_ptr<CrossSection> cross_section_square(double x_size, double y_size, bool center);
+
+    /// @brief Create a circle
+    /// @param radius Radius of the circle
+    /// @param circular_segments Number of segments
+    std::unique_ptr<CrossSection> cross_section_circle(double radius, std::int32_t circular_segments);
+
+    /// @brief Create a cross section from polygons
+    /// @param polygons Contours, overlaps are resolved by the fill rule
+    /// @param fill_rule 0: even-odd, 1: non-zero, 2: positive, 3: negative
+    std::unique_ptr<CrossSection> cross_section_from_polygons(const Polygons &polygons, std::uint8_t fill_rule);
+
+    /// @brief Perform a union operation on cross sections
+    std::unique_ptr<CrossSection> cross_section_union(const CrossSection &a, const CrossSection &b);
+
+    /// @brief Perform an intersection operation on cross sections
+    std::unique_ptr<CrossSection> cross_section_intersection(const CrossSection &a, const CrossSection &b);
+
+    /// @brief Perform a difference operation on cross sections
+    std::unique_ptr<CrossSection> cross_section_difference(const CrossSection &a, const CrossSection &b);
 } // namespace manifold_rs
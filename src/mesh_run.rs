@@ -0,0 +1,59 @@
+// Copyright © 2025 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runs of triangles originating from the same input mesh
+
+use crate::Transform;
+
+/// Triangles of a mesh which originate from the same input manifold.
+///
+/// See [`crate::Manifold::as_original`] for how to assign IDs to input manifolds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeshRun {
+    /// ID of the original manifold.
+    pub original_id: u32,
+    /// Range of triangle indices of this run.
+    pub triangles: std::ops::Range<usize>,
+    /// Transformation from the original manifold into the mesh.
+    pub transform: Transform,
+}
+
+/// Iterator over the [`MeshRun`]s of a [`crate::Mesh`].
+pub struct MeshRuns<'a> {
+    pub(crate) run_index: &'a [u32],
+    pub(crate) run_original_id: &'a [u32],
+    pub(crate) run_transform: &'a [f32],
+    pub(crate) num_tri: usize,
+    pub(crate) range: std::ops::Range<usize>,
+}
+
+impl Iterator for MeshRuns<'_> {
+    type Item = MeshRun;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.range.next()?;
+        let start = self.run_index[i] as usize / 3;
+        // The last end index may be omitted
+        let end = self
+            .run_index
+            .get(i + 1)
+            .map_or(self.num_tri, |&end| end as usize / 3);
+        let transform = match self.run_transform.get(i * 12..(i + 1) * 12) {
+            Some(m) => Transform(std::array::from_fn(|c| {
+                std::array::from_fn(|r| m[c * 3 + r] as f64)
+            })),
+            None => Transform::IDENTITY,
+        };
+        Some(MeshRun {
+            original_id: self.run_original_id[i],
+            triangles: start..end,
+            transform,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl ExactSizeIterator for MeshRuns<'_> {}
@@ -0,0 +1,113 @@
+// Copyright © 2025 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Operators for boolean operations and transformations of manifolds
+//!
+//! `a + b` and `a | b` are the union, `a - b` the difference and `a & b` the
+//! intersection. `a * transform` applies a [`Transform`].
+
+use crate::{Manifold, Transform};
+use std::ops::{Add, AddAssign, BitAnd, BitOr, Mul, Sub, SubAssign};
+
+/// Implement a binary operator for all combinations of owned and borrowed manifolds.
+macro_rules! impl_boolean_op {
+    ($trait:ident, $fn:ident, $method:ident) => {
+        impl $trait<&Manifold> for &Manifold {
+            type Output = Manifold;
+
+            fn $fn(self, rhs: &Manifold) -> Manifold {
+                self.$method(rhs)
+            }
+        }
+
+        impl $trait<Manifold> for &Manifold {
+            type Output = Manifold;
+
+            fn $fn(self, rhs: Manifold) -> Manifold {
+                self.$method(&rhs)
+            }
+        }
+
+        impl $trait<&Manifold> for Manifold {
+            type Output = Manifold;
+
+            fn $fn(self, rhs: &Manifold) -> Manifold {
+                self.$method(rhs)
+            }
+        }
+
+        impl $trait<Manifold> for Manifold {
+            type Output = Manifold;
+
+            fn $fn(self, rhs: Manifold) -> Manifold {
+                self.$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_boolean_op!(Add, add, union);
+impl_boolean_op!(BitOr, bitor, union);
+impl_boolean_op!(Sub, sub, difference);
+impl_boolean_op!(BitAnd, bitand, intersection);
+
+impl AddAssign<&Manifold> for Manifold {
+    fn add_assign(&mut self, rhs: &Manifold) {
+        *self = self.union(rhs);
+    }
+}
+
+impl AddAssign<Manifold> for Manifold {
+    fn add_assign(&mut self, rhs: Manifold) {
+        *self += &rhs;
+    }
+}
+
+impl SubAssign<&Manifold> for Manifold {
+    fn sub_assign(&mut self, rhs: &Manifold) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl SubAssign<Manifold> for Manifold {
+    fn sub_assign(&mut self, rhs: Manifold) {
+        *self -= &rhs;
+    }
+}
+
+impl Mul<Transform> for &Manifold {
+    type Output = Manifold;
+
+    fn mul(self, rhs: Transform) -> Manifold {
+        self.transform(rhs)
+    }
+}
+
+impl Mul<Transform> for Manifold {
+    type Output = Manifold;
+
+    fn mul(self, rhs: Transform) -> Manifold {
+        self.transform(rhs)
+    }
+}
+
+#[test]
+fn test_operators() {
+    let a = Manifold::cube(2.0, 2.0, 2.0);
+    let b = Manifold::cube(2.0, 2.0, 2.0).translate(1.0, 0.0, 0.0);
+
+    assert!(((&a + &b).volume() - 12.0).abs() < 1e-9);
+    assert!(((&a | &b).volume() - 12.0).abs() < 1e-9);
+    assert!(((&a - &b).volume() - 4.0).abs() < 1e-9);
+    assert!(((&a & &b).volume() - 4.0).abs() < 1e-9);
+    assert!(((a.clone() - b.clone()).volume() - 4.0).abs() < 1e-9);
+
+    let mut c = a.clone();
+    c += &b;
+    c -= Manifold::cube(1.0, 2.0, 2.0);
+    assert!((c.volume() - 8.0).abs() < 1e-9);
+
+    let mut translation = Transform::IDENTITY;
+    translation.0[3] = [1.0, 0.0, 0.0];
+    assert_eq!((&a * translation).bounding_box(), b.bounding_box());
+}
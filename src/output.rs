@@ -155,12 +155,12 @@ pub fn write_ply(
     writeln!(writer, "comment written by rust-sdf")?;
 
     assert!(
-        vertices.len() % num_props == 0,
+        vertices.len().is_multiple_of(num_props),
         "Number of vertices elements must be divisible by num_props"
     );
 
     assert!(
-        indices.len() % 3 == 0,
+        indices.len().is_multiple_of(3),
         "Number of indices must be divisible by 3"
     );
 
@@ -214,7 +214,12 @@ pub trait WritePly {
 
 impl WriteStl for Mesh {
     fn write_stl(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
-        write_stl(&self.vertices(), self.num_props(), &self.indices(), writer)
+        write_stl(
+            self.vertex_slice(),
+            self.num_props(),
+            self.index_slice(),
+            writer,
+        )
     }
 }
 
@@ -226,7 +231,12 @@ impl WriteStl for Manifold {
 
 impl WritePly for Mesh {
     fn write_ply(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
-        write_ply(&self.vertices(), self.num_props(), &self.indices(), writer)
+        write_ply(
+            self.vertex_slice(),
+            self.num_props(),
+            self.index_slice(),
+            writer,
+        )
     }
 }
 
@@ -0,0 +1,74 @@
+// Copyright © 2025 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! 2D coordinate frames of planes in 3D space
+
+use crate::{InputError, Transform};
+
+/// Coordinate frame of a plane in 3D space.
+///
+/// Maps the 2D coordinates of polygons returned by [`crate::Manifold::slice_plane`]
+/// and [`crate::Manifold::project_along`] back into 3D space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlaneFrame {
+    /// Origin of the 2D coordinates.
+    pub origin: [f64; 3],
+    /// Direction of the 2D x axis.
+    pub x_axis: [f64; 3],
+    /// Direction of the 2D y axis.
+    pub y_axis: [f64; 3],
+    /// Unit normal of the plane.
+    pub normal: [f64; 3],
+}
+
+impl PlaneFrame {
+    /// Create the frame of the plane at signed distance `offset` from the origin along `normal`.
+    ///
+    /// The normal does not need to be normalized. For the normal `(0, 0, 1)`
+    /// the x and y axes match the global ones.
+    pub fn new(normal: [f64; 3], offset: f64) -> Result<Self, InputError> {
+        let length = normal.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if !length.is_finite() || length == 0.0 {
+            return Err(InputError::InvalidDirection);
+        }
+        let n = normal.map(|x| x / length);
+
+        // Rotation taking n onto the z axis, by Rodrigues' formula
+        let c = n[2];
+        let rows = if c < -1.0 + 1e-12 {
+            // Half turn around the x axis
+            [[1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, -1.0]]
+        } else {
+            // Rotation axis n × z = (n.y, -n.x, 0)
+            let (vx, vy) = (n[1], -n[0]);
+            let k = 1.0 / (1.0 + c);
+            [
+                [1.0 - vy * vy * k, vx * vy * k, vy],
+                [vx * vy * k, 1.0 - vx * vx * k, -vx],
+                [-vy, vx, c],
+            ]
+        };
+
+        Ok(Self {
+            origin: n.map(|x| x * offset),
+            x_axis: rows[0],
+            y_axis: rows[1],
+            normal: n,
+        })
+    }
+
+    /// Map 2D coordinates in the plane to a 3D point.
+    pub fn to_3d(&self, p: [f64; 2]) -> [f64; 3] {
+        std::array::from_fn(|i| self.origin[i] + p[0] * self.x_axis[i] + p[1] * self.y_axis[i])
+    }
+
+    /// Rotation which maps the plane onto a plane parallel to the XY plane.
+    pub(crate) fn to_local(self) -> Transform {
+        Transform([
+            [self.x_axis[0], self.y_axis[0], self.normal[0]],
+            [self.x_axis[1], self.y_axis[1], self.normal[1]],
+            [self.x_axis[2], self.y_axis[2], self.normal[2]],
+            [0.0; 3],
+        ])
+    }
+}
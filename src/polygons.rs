@@ -0,0 +1,222 @@
+// Copyright © 2025 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! 2D polygons
+
+use crate::{ffi, Rect};
+
+/// Manifold rust wrapper for C++ polygons object.
+///
+/// Each polygon is a closed contour of x, y points. Outlines are expected to be
+/// counter-clockwise and holes clockwise.
+pub struct Polygons(pub(crate) cxx::UniquePtr<ffi::Polygons>);
+
+// SAFETY: The C++ polygons are plain data in standard vectors, only modified through `&mut self`.
+unsafe impl Send for Polygons {}
+unsafe impl Sync for Polygons {}
+
+impl Polygons {
+    /// Create empty polygons.
+    pub fn new() -> Self {
+        Self(ffi::polygons_empty())
+    }
+
+    /// Append a polygon.
+    pub fn push(&mut self, polygon: &[[f64; 2]]) {
+        self.0.pin_mut().push(polygon.as_flattened())
+    }
+
+    /// Get the number of polygons.
+    pub fn size(&self) -> usize {
+        self.0.size()
+    }
+
+    /// Get the number of polygons.
+    pub fn len(&self) -> usize {
+        self.size()
+    }
+
+    /// Are there no polygons?
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Get the flat x, y coordinates of a polygon.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn get_as_slice(&self, i: usize) -> &[f64] {
+        assert!(
+            i < self.size(),
+            "polygon index {i} out of bounds, there are {} polygons",
+            self.size()
+        );
+        self.0.get_as_slice(i)
+    }
+
+    /// Get the points of a polygon.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn get(&self, i: usize) -> &[[f64; 2]] {
+        self.get_as_slice(i).as_chunks::<2>().0
+    }
+
+    /// Iterate over the points of all polygons.
+    pub fn iter(&self) -> PolygonsIter<'_> {
+        PolygonsIter {
+            polygons: self,
+            range: 0..self.size(),
+        }
+    }
+
+    /// Get the signed area of a polygon, positive if counter-clockwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn signed_area(&self, i: usize) -> f64 {
+        let points = self.get(i);
+        (0..points.len())
+            .map(|j| {
+                let [x0, y0] = points[j];
+                let [x1, y1] = points[(j + 1) % points.len()];
+                x0 * y1 - x1 * y0
+            })
+            .sum::<f64>()
+            / 2.0
+    }
+
+    /// Is a polygon oriented counter-clockwise, i.e. an outline?
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn is_counter_clockwise(&self, i: usize) -> bool {
+        self.signed_area(i) > 0.0
+    }
+
+    /// Get the area enclosed by all polygons.
+    ///
+    /// Clockwise holes are subtracted from counter-clockwise outlines.
+    pub fn area(&self) -> f64 {
+        (0..self.size()).map(|i| self.signed_area(i)).sum()
+    }
+
+    /// Triangulate the area enclosed by the polygons.
+    ///
+    /// Returns counter-clockwise triangles whose indices refer to the points of all
+    /// polygons in order. Points closer than `epsilon` may be merged, a negative
+    /// `epsilon` selects a default based on the extent of the polygons.
+    pub fn triangulate(&self, epsilon: f64) -> Vec<[u32; 3]> {
+        let indices_binding = self.0.triangulate(epsilon);
+        let indices = indices_binding.as_ref().unwrap().as_slice();
+        indices.as_chunks::<3>().0.to_vec()
+    }
+
+    /// Get the axis-aligned bounding rectangle of all points.
+    pub fn bounds(&self) -> Rect {
+        self.iter()
+            .flatten()
+            .fold(Rect::EMPTY, |rect, point| rect.union_point(*point))
+    }
+}
+
+impl Default for Polygons {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Polygons {
+    fn clone(&self) -> Self {
+        Self(self.0.copy())
+    }
+}
+
+impl std::fmt::Debug for Polygons {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a Polygons {
+    type Item = &'a [[f64; 2]];
+    type IntoIter = PolygonsIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the points of each polygon in [`Polygons`].
+pub struct PolygonsIter<'a> {
+    polygons: &'a Polygons,
+    range: std::ops::Range<usize>,
+}
+
+impl<'a> Iterator for PolygonsIter<'a> {
+    type Item = &'a [[f64; 2]];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|i| self.polygons.get(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl ExactSizeIterator for PolygonsIter<'_> {}
+
+/// Collect polygons from their points
+impl<'a> FromIterator<&'a [[f64; 2]]> for Polygons {
+    fn from_iter<I: IntoIterator<Item = &'a [[f64; 2]]>>(iter: I) -> Self {
+        let mut polygons = Self::new();
+        iter.into_iter().for_each(|polygon| polygons.push(polygon));
+        polygons
+    }
+}
+
+#[test]
+fn test_polygons() {
+    let mut polygons = Polygons::new();
+    assert!(polygons.is_empty());
+    assert!(polygons.bounds().is_empty());
+
+    polygons.push(&[[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]]);
+    polygons.push(&[[1.0, 1.0], [1.0, 2.0], [2.0, 2.0], [2.0, 1.0]]);
+    assert_eq!(polygons.len(), 2);
+    assert_eq!(polygons.get(1)[1], [1.0, 2.0]);
+    assert!(polygons.is_counter_clockwise(0));
+    assert!(!polygons.is_counter_clockwise(1));
+    assert_eq!(polygons.area(), 15.0);
+
+    let bounds = polygons.bounds();
+    assert_eq!(bounds.min, [0.0, 0.0]);
+    assert_eq!(bounds.max, [4.0, 4.0]);
+
+    let copy = polygons.clone();
+    polygons.push(&[[5.0, 0.0], [6.0, 0.0], [6.0, 1.0]]);
+    assert_eq!(copy.len(), 2);
+    assert_eq!(
+        polygons.iter().map(|p| p.len()).collect::<Vec<_>>(),
+        [4, 4, 3]
+    );
+
+    let triangles = copy.triangulate(-1.0);
+    assert_eq!(triangles.len(), 8);
+    assert!(triangles.iter().flatten().all(|&i| i < 8));
+
+    let extruded = crate::Manifold::extrude_shape(&copy, 1.0, 0, 0.0, 1.0, 1.0).unwrap();
+    assert!((extruded.volume() - 15.0).abs() < 1e-9);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_polygons_out_of_bounds() {
+    let polygons = Polygons::from_iter([[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]].as_slice()]);
+    polygons.get(1);
+}
@@ -0,0 +1,106 @@
+// Copyright © 2025 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Affine transformations
+
+/// Affine 3D transformation as a 4x3 matrix.
+///
+/// The matrix is stored as four columns: the first three hold the linear part,
+/// the last one holds the translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform(pub [[f64; 3]; 4]);
+
+impl Transform {
+    /// Identity transformation.
+    pub const IDENTITY: Self = Self([
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0],
+        [0.0, 0.0, 0.0],
+    ]);
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<[[f64; 3]; 4]> for Transform {
+    fn from(columns: [[f64; 3]; 4]) -> Self {
+        Self(columns)
+    }
+}
+
+impl From<&[[f64; 3]; 4]> for Transform {
+    fn from(columns: &[[f64; 3]; 4]) -> Self {
+        Self(*columns)
+    }
+}
+
+/// Convert a homogeneous matrix, the projective row is ignored.
+#[cfg(feature = "cgmath")]
+impl From<cgmath::Matrix4<f64>> for Transform {
+    fn from(m: cgmath::Matrix4<f64>) -> Self {
+        Self([
+            m.x.truncate().into(),
+            m.y.truncate().into(),
+            m.z.truncate().into(),
+            m.w.truncate().into(),
+        ])
+    }
+}
+
+#[cfg(feature = "cgmath")]
+impl From<cgmath::Matrix3<f64>> for Transform {
+    fn from(m: cgmath::Matrix3<f64>) -> Self {
+        Self([m.x.into(), m.y.into(), m.z.into(), [0.0; 3]])
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DAffine3> for Transform {
+    fn from(a: glam::DAffine3) -> Self {
+        Self([
+            a.matrix3.x_axis.to_array(),
+            a.matrix3.y_axis.to_array(),
+            a.matrix3.z_axis.to_array(),
+            a.translation.to_array(),
+        ])
+    }
+}
+
+/// Convert a homogeneous matrix, the projective row is ignored.
+#[cfg(feature = "glam")]
+impl From<glam::DMat4> for Transform {
+    fn from(m: glam::DMat4) -> Self {
+        Self([
+            m.x_axis.truncate().to_array(),
+            m.y_axis.truncate().to_array(),
+            m.z_axis.truncate().to_array(),
+            m.w_axis.truncate().to_array(),
+        ])
+    }
+}
+
+/// Convert a homogeneous matrix, the projective row is ignored.
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Matrix4<f64>> for Transform {
+    fn from(m: nalgebra::Matrix4<f64>) -> Self {
+        Self(std::array::from_fn(|c| std::array::from_fn(|r| m[(r, c)])))
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Affine3<f64>> for Transform {
+    fn from(a: nalgebra::Affine3<f64>) -> Self {
+        a.to_homogeneous().into()
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Isometry3<f64>> for Transform {
+    fn from(i: nalgebra::Isometry3<f64>) -> Self {
+        i.to_homogeneous().into()
+    }
+}